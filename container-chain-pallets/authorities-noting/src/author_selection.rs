@@ -0,0 +1,102 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Strategies deciding which of the noted authorities is expected to author each slot
+
+use {
+    alloc::vec::Vec, core::marker::PhantomData, frame_support::traits::Randomness,
+    frame_system::pallet_prelude::BlockNumberFor, parity_scale_codec::Encode, sp_core::blake2_256,
+};
+
+/// Selects the authority expected to author a slot among the noted authorities
+pub trait AuthorSelectionStrategy<AuthorityId: Clone> {
+    /// Authorities noted for `session_index` in the order in which they author consecutive
+    /// slots, the author of `slot` being at `slot % authorities.len()`. Allows selecting the
+    /// authors of several slots without computing the ordering again.
+    fn slot_ordering(authorities: &[AuthorityId], session_index: u32) -> Vec<AuthorityId>;
+
+    /// Authority expected to author `slot`, given the authorities noted for `session_index`.
+    /// Returns `None` if `authorities` is empty.
+    fn select_author(
+        authorities: &[AuthorityId],
+        slot: u32,
+        session_index: u32,
+    ) -> Option<AuthorityId> {
+        let mut ordering = Self::slot_ordering(authorities, session_index);
+        if ordering.is_empty() {
+            return None;
+        }
+
+        let index = (slot as usize) % ordering.len();
+        Some(ordering.swap_remove(index))
+    }
+}
+
+/// Authorities author slots in turns, in the order in which they were noted
+pub struct RoundRobin;
+
+impl<AuthorityId: Clone> AuthorSelectionStrategy<AuthorityId> for RoundRobin {
+    fn slot_ordering(authorities: &[AuthorityId], _session_index: u32) -> Vec<AuthorityId> {
+        authorities.to_vec()
+    }
+}
+
+/// Round robin shifted by the orchestrator session index, so that the same authority does not
+/// always author the first slot after a rotation
+pub struct SessionOffsetRoundRobin;
+
+impl<AuthorityId: Clone> AuthorSelectionStrategy<AuthorityId> for SessionOffsetRoundRobin {
+    fn slot_ordering(authorities: &[AuthorityId], session_index: u32) -> Vec<AuthorityId> {
+        let mut ordering = authorities.to_vec();
+        if !ordering.is_empty() {
+            let offset = (session_index as usize) % ordering.len();
+            ordering.rotate_left(offset);
+        }
+
+        ordering
+    }
+}
+
+/// Round robin over an ordering of the authorities shuffled with `R`, seeded with the
+/// orchestrator session index. `R` must return the same output for the whole session for the
+/// ordering to be stable. With [`crate::RelayRandomness`], the ordering also changes when a new
+/// relay epoch starts.
+pub struct RandomnessSeeded<T, R>(PhantomData<(T, R)>);
+
+impl<T, R, AuthorityId> AuthorSelectionStrategy<AuthorityId> for RandomnessSeeded<T, R>
+where
+    T: frame_system::Config,
+    R: Randomness<T::Hash, BlockNumberFor<T>>,
+    AuthorityId: Clone,
+{
+    fn slot_ordering(authorities: &[AuthorityId], session_index: u32) -> Vec<AuthorityId> {
+        if authorities.is_empty() {
+            return Vec::new();
+        }
+
+        let (seed, _) = R::random(&(b"authorities-noting", session_index).encode());
+
+        let mut ordering: Vec<(usize, [u8; 32])> = (0..authorities.len())
+            .map(|index| (index, blake2_256(&(seed, index as u32).encode())))
+            .collect();
+        ordering.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+        ordering
+            .into_iter()
+            .map(|(index, _)| authorities[index].clone())
+            .collect()
+    }
+}
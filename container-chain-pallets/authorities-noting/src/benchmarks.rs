@@ -18,62 +18,150 @@
 
 //! Benchmarking
 use {
-    crate::{Call, Config, Pallet, ParaId},
+    crate::{
+        mock_proof::{CONTAINER_CHAIN_PARA_ID, ORCHESTRATOR_PARA_ID, SESSION_INDEX},
+        Authorities, AuthoritiesHistory, AuthoritiesHistorySessions, AuthoritiesOf,
+        AuthoritiesOverride, Call, ChainMode, Config, GracePeriodExpiresAt, HaltReason, Halted,
+        LastNotedOrigin, NotedOrigin, OrchestratorChainMode, OrchestratorParaId, Pallet, ParaId,
+        PinnedAuthorities,
+    },
     alloc::{vec, vec::Vec},
     cumulus_pallet_parachain_system::RelaychainStateProvider,
     frame_benchmarking::{account, benchmarks},
-    frame_system::RawOrigin,
+    frame_system::{pallet_prelude::BlockNumberFor, RawOrigin},
     nimbus_primitives::NimbusId,
     sp_core::crypto::ByteArray,
 };
 
 mod test_sproof {
-    use sp_trie::StorageProof;
+    use {parity_scale_codec::Encode, sp_trie::StorageProof};
 
     /// Mocked proof because we cannot build proofs in a no-std environment.
-    /// Only stores the number of parachains, and reads a previously encoded proof for that number
-    /// of items from `crate::mock_proof`.
+    /// Only stores the number of authorities and the size of the proof, and reads a previously
+    /// encoded proof for that number of authorities from `crate::mock_proof`.
     #[derive(Clone, Default)]
-    pub struct ParaHeaderSproofBuilder;
+    pub struct ParaHeaderSproofBuilder {
+        pub num_authorities: u32,
+        pub proof_size: u32,
+    }
 
     impl ParaHeaderSproofBuilder {
         pub fn into_state_root_and_proof(
             self,
         ) -> (cumulus_primitives_core::relay_chain::Hash, StorageProof) {
-            let encoded = crate::mock_proof::ENCODED_PROOFS[0].1;
-            let root = hex::decode(encoded.0).unwrap();
-            let proof = StorageProof::new(encoded.1.iter().map(|s| hex::decode(s).unwrap()));
+            let encoded = crate::mock_proof::ENCODED_PROOFS[self.num_authorities as usize].1;
 
-            (<[u8; 32]>::try_from(root).unwrap().into(), proof)
+            decode_and_pad(encoded, self.proof_size)
         }
     }
 
     /// Mocked proof because we cannot build proofs in a no-std environment.
-    /// Reads a previously encoded proof from `crate::mock_proof`.
+    /// Only stores the number of authorities and the size of the proof, and reads a previously
+    /// encoded proof for that number of authorities from `crate::mock_proof`.
     #[derive(Clone, Default)]
-    pub struct AuthorityAssignmentSproofBuilder;
+    pub struct AuthorityAssignmentSproofBuilder {
+        pub num_authorities: u32,
+        pub proof_size: u32,
+    }
 
     impl AuthorityAssignmentSproofBuilder {
         pub fn into_state_root_and_proof(
             self,
         ) -> (cumulus_primitives_core::relay_chain::Hash, StorageProof) {
-            let encoded = crate::mock_proof::ENCODED_PROOFS[0].2;
-            let root = hex::decode(encoded.0).unwrap();
-            let proof = StorageProof::new(encoded.1.iter().map(|s| hex::decode(s).unwrap()));
+            let encoded = crate::mock_proof::ENCODED_PROOFS[self.num_authorities as usize].2;
+
+            decode_and_pad(encoded, self.proof_size)
+        }
+    }
 
-            (<[u8; 32]>::try_from(root).unwrap().into(), proof)
+    /// Decodes a proof from `crate::mock_proof`, adding an unused node to it if needed so that
+    /// it is at least `proof_size` bytes long once encoded
+    fn decode_and_pad(
+        (root, nodes): (&str, &[&str]),
+        proof_size: u32,
+    ) -> (cumulus_primitives_core::relay_chain::Hash, StorageProof) {
+        let root = hex::decode(root).unwrap();
+        let mut nodes: alloc::vec::Vec<_> = nodes.iter().map(|s| hex::decode(s).unwrap()).collect();
+
+        let encoded_size = nodes.encoded_size() as u32;
+        if proof_size > encoded_size {
+            nodes.push(alloc::vec![0; (proof_size - encoded_size) as usize]);
         }
+
+        (
+            <[u8; 32]>::try_from(root).unwrap().into(),
+            StorageProof::new(nodes),
+        )
     }
 }
 
+/// Upper bound of the proof sizes, in bytes, used to benchmark `set_latest_authorities_data`
+const MAX_PROOF_SIZE: u32 = 100_000;
+
 benchmarks! {
+    // The proofs of `crate::mock_proof` assign the authorities to para `CONTAINER_CHAIN_PARA_ID`,
+    // so the runtime must be benchmarked with that `SelfParaId`. With any other para id no
+    // authorities would be found in the proofs, which is a lighter path than the one weighed
+    // here, so the benchmark refuses to run.
     set_latest_authorities_data {
-        // TODO: this could measure the proof size
-        let sproof_builder_relay = test_sproof::ParaHeaderSproofBuilder;
-        let sproof_builder_orchestrator = test_sproof::AuthorityAssignmentSproofBuilder;
+        // Size of the relay chain state proof, in bytes
+        let r in 0..MAX_PROOF_SIZE;
+        // Size of the orchestrator chain state proof, in bytes
+        let o in 0..MAX_PROOF_SIZE;
+        // Number of authorities in the orchestrator assignment, limited by the encoded proofs
+        let x in 0..T::MaxAuthorities::get().min(crate::mock_proof::ENCODED_PROOFS.len() as u32 - 1);
+
+        assert_eq!(
+            T::SelfParaId::get(),
+            ParaId::from(CONTAINER_CHAIN_PARA_ID),
+            "the benchmark proofs only assign authorities to para {}",
+            CONTAINER_CHAIN_PARA_ID,
+        );
+
+        let sproof_builder_relay = test_sproof::ParaHeaderSproofBuilder {
+            num_authorities: x,
+            proof_size: r,
+        };
+        let sproof_builder_orchestrator = test_sproof::AuthorityAssignmentSproofBuilder {
+            num_authorities: x,
+            proof_size: o,
+        };
+
+        // Worst case: all the noted authorities are replaced, which removes their session keys,
+        // the oldest session of a full history is pruned, the chain is resumed and an override
+        // expires. Removing the author statistics of the pruned session is weighed separately.
+        ChainMode::<T>::put(OrchestratorChainMode::Parachain);
+        OrchestratorParaId::<T>::put(ParaId::from(ORCHESTRATOR_PARA_ID));
+        let old_authorities = AuthoritiesOf::<T>::truncate_from(
+            (0..x)
+                .map(|i| account::<T::AuthorityId>("old authority", i, 0u32))
+                .collect(),
+        );
+        Authorities::<T>::put(&old_authorities);
+        let depth = T::AuthoritiesHistoryDepth::get().min(SESSION_INDEX);
+        let mut sessions = AuthoritiesHistorySessions::<T>::get();
+        for session in SESSION_INDEX - depth..SESSION_INDEX {
+            let _ = sessions.try_push((session, 0u32.into()));
+            AuthoritiesHistory::<T>::insert(session, &old_authorities);
+        }
+        AuthoritiesHistorySessions::<T>::put(sessions);
+        LastNotedOrigin::<T>::put(NotedOrigin {
+            orchestrator_session_index: SESSION_INDEX - 1,
+            orchestrator_block_number: Some(0),
+            orchestrator_block_hash: Some(Default::default()),
+            relay_parent_number: 0,
+            relay_parent_storage_root: Default::default(),
+        });
+        GracePeriodExpiresAt::<T>::put(BlockNumberFor::<T>::from(10u32));
+        Halted::<T>::put(HaltReason::Paused);
+        AuthoritiesOverride::<T>::put(PinnedAuthorities {
+            authorities: old_authorities,
+            expires_at: u32::MAX.into(),
+            until_session: Some(SESSION_INDEX),
+        });
 
         let (relay_root, relay_proof) = sproof_builder_relay.into_state_root_and_proof();
-        let (orchestrator_root, orchestrator_proof) = sproof_builder_orchestrator.into_state_root_and_proof();
+        let (_orchestrator_root, orchestrator_proof) = sproof_builder_orchestrator.into_state_root_and_proof();
 
         let data = ccp_authorities_noting_inherent::ContainerChainAuthoritiesInherentData {
             relay_chain_state: relay_proof,
@@ -85,15 +173,20 @@ benchmarks! {
             number: 0,
         });
     }: _(RawOrigin::None, data)
+    verify {
+        assert_eq!(Pallet::<T>::authorities().len() as u32, x);
+        assert!(Pallet::<T>::authorities_override().is_none());
+        assert!(!Pallet::<T>::is_halted());
+    }
 
     set_authorities {
         // Depend on the number of authorities
-        let x in 0..10;
+        let x in 0..T::MaxAuthorities::get();
 
         let mut authorities = vec![];
 
-        for _ in 0..x {
-            let author: T::AuthorityId = account::<T::AuthorityId>("account id", x, 0u32);
+        for i in 0..x {
+            let author: T::AuthorityId = account::<T::AuthorityId>("account id", i, 0u32);
             authorities.push(author);
         }
     }: _(RawOrigin::Root, authorities)
@@ -101,6 +194,34 @@ benchmarks! {
     set_orchestrator_para_id {
     }: _(RawOrigin::Root, ParaId::new(2000))
 
+    set_chain_mode {
+    }: _(RawOrigin::Root, Some(OrchestratorChainMode::Parachain))
+
+    set_authorities_override {
+        // Depend on the number of authorities
+        let x in 1..T::MaxAuthorities::get();
+
+        let mut authorities = vec![];
+
+        for i in 0..x {
+            let author: T::AuthorityId = account::<T::AuthorityId>("account id", i, 0u32);
+            authorities.push(author);
+        }
+    }: _(RawOrigin::Root, authorities, 10u32.into(), Some(1))
+
+    clear_authorities_override {
+        let author: T::AuthorityId = account::<T::AuthorityId>("account id", 0, 0u32);
+        Pallet::<T>::set_authorities_override(
+            RawOrigin::Root.into(),
+            vec![author],
+            10u32.into(),
+            None,
+        )?;
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(Pallet::<T>::authorities_override().is_none());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::new_test_ext(),
@@ -0,0 +1,81 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! `FindAuthor` implementations backed by the noted authorities, for pallets such as the EVM
+//! ones that need the author of the current block
+
+use {
+    crate::{Config, Pallet},
+    core::marker::PhantomData,
+    frame_support::{traits::FindAuthor, ConsensusEngineId},
+    nimbus_primitives::{AccountLookup, NimbusId, NIMBUS_ENGINE_ID},
+    parity_scale_codec::Decode,
+    sp_consensus_aura::{Slot, AURA_ENGINE_ID},
+    sp_core::{crypto::ByteArray, H160},
+};
+
+/// Finds the author in the nimbus pre-runtime digest. If there is none, the author is the
+/// authority expected to author the slot of the aura pre-runtime digest.
+pub struct FindNimbusAuthor<T>(PhantomData<T>);
+
+impl<T: Config> FindAuthor<T::AuthorityId> for FindNimbusAuthor<T> {
+    fn find_author<'a, I>(digests: I) -> Option<T::AuthorityId>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        let mut slot = None;
+
+        for (engine_id, mut data) in digests {
+            if engine_id == NIMBUS_ENGINE_ID {
+                if let Ok(author) = T::AuthorityId::decode(&mut data) {
+                    return Some(author);
+                }
+            } else if engine_id == AURA_ENGINE_ID {
+                slot = Slot::decode(&mut data).ok();
+            }
+        }
+
+        let slot: u64 = slot?.into();
+        Pallet::<T>::expected_author(slot as u32)
+    }
+}
+
+/// Maps the author found by `Inner` to an account with `Lookup`, for pallets that need an
+/// `AccountId` or an `H160` author
+pub struct FindMappedAuthor<Inner, Lookup>(PhantomData<(Inner, Lookup)>);
+
+impl<AccountId, Inner, Lookup> FindAuthor<AccountId> for FindMappedAuthor<Inner, Lookup>
+where
+    Inner: FindAuthor<NimbusId>,
+    Lookup: AccountLookup<AccountId>,
+{
+    fn find_author<'a, I>(digests: I) -> Option<AccountId>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        Lookup::lookup_account(&Inner::find_author(digests)?)
+    }
+}
+
+/// Maps a `NimbusId` to the `H160` made of bytes 4 to 24 of the key, as frontier does for aura
+/// authorities
+pub struct TruncatedH160Lookup;
+
+impl AccountLookup<H160> for TruncatedH160Lookup {
+    fn lookup_account(author: &NimbusId) -> Option<H160> {
+        Some(H160::from_slice(&author.as_slice()[4..24]))
+    }
+}
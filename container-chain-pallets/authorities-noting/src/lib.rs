@@ -25,6 +25,21 @@
 //! A second storage proof is verified against the storage root of the orchestrator chain. From
 //! this the collator-assignation is read, and the authorities assigned to these container-chain
 //! are retrieved and stored
+//!
+//! The assignment for the next orchestrator session is read from the same proof when available,
+//! so that the upcoming authorities are known before the session rotation happens. So are the
+//! orchestrator session keys of the authorities, if `OrchestratorSessionKeys` is not `()`
+//!
+//! If the authorities cannot be noted, the last noted ones remain valid for
+//! `AuthoritiesGracePeriod` blocks before being cleared
+//!
+//! The registrar state of the orchestrator is read from the same proof when available. While it
+//! shows this chain as paused or not registered, the chain is halted: [`NotHalted`] only lets
+//! inherents through until the orchestrator enables it again
+//!
+//! The epoch randomness of the relay chain is also read from the relay proof when available, and
+//! exposed through [`RelayRandomness`]. The relay slot is read as well, to optionally check
+//! that the time of each block does not drift too far from it
 
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
@@ -35,6 +50,16 @@ mod mock;
 #[cfg(test)]
 mod tests;
 pub mod weights;
+
+pub mod author_selection;
+pub mod find_author;
+pub mod migrations;
+pub mod randomness;
+pub use author_selection::{
+    AuthorSelectionStrategy, RandomnessSeeded, RoundRobin, SessionOffsetRoundRobin,
+};
+pub use find_author::{FindMappedAuthor, FindNimbusAuthor, TruncatedH160Lookup};
+pub use randomness::{NotedRandomness, RelayRandomness};
 pub use weights::WeightInfo;
 
 #[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -49,6 +74,7 @@ pub use pallet::*;
 use {
     alloc::{borrow::Cow, vec::Vec},
     ccp_authorities_noting_inherent::INHERENT_IDENTIFIER,
+    ccp_authorities_noting_runtime_api::BlockProductionStats,
     cumulus_pallet_parachain_system::RelaychainStateProvider,
     cumulus_primitives_core::{
         relay_chain::{BlakeTwo256, BlockNumber, HeadData},
@@ -56,18 +82,67 @@ use {
     },
     dp_chain_state_snapshot::*,
     dp_collator_assignment::AssignedCollators,
-    dp_core::well_known_keys,
-    frame_support::{dispatch::PostDispatchInfo, pallet_prelude::*, traits::Get, Hashable},
+    dp_core::{well_known_keys, ParaInfo},
+    frame_support::{
+        dispatch::PostDispatchInfo,
+        pallet_prelude::*,
+        traits::{Contains, FindAuthor, Get, UnixTime},
+        Hashable,
+    },
     frame_system::pallet_prelude::*,
-    parity_scale_codec::{Decode, Encode},
+    nimbus_primitives::NIMBUS_KEY_ID,
+    parity_scale_codec::{Decode, DecodeWithMemTracking, Encode},
+    serde::{Deserialize, Serialize},
+    sp_core::{crypto::ByteArray, H256},
     sp_inherents::{InherentIdentifier, IsFatalError},
-    sp_runtime::traits::Hash as HashT,
+    sp_runtime::traits::{Header as HeaderT, Saturating, Zero},
 };
 
 pub trait GetContainerChains {
     fn container_chains() -> Vec<ParaId>;
 }
 
+/// Hook called when the authorities noted by the pallet change, either through the inherent or
+/// through `set_authorities`
+pub trait OnAuthoritiesChanged<AuthorityId> {
+    /// `session_index` is the orchestrator session of the new authorities, or of the last
+    /// noted ones if they were set through `set_authorities`. Returns the weight consumed.
+    fn on_authorities_changed(
+        old_authorities: &[AuthorityId],
+        new_authorities: &[AuthorityId],
+        session_index: Option<u32>,
+    ) -> Weight;
+
+    /// Upper bound of the weight of `on_authorities_changed`, charged by the calls that may
+    /// call it
+    fn max_weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AuthorityId> OnAuthoritiesChanged<AuthorityId> for Tuple {
+    fn on_authorities_changed(
+        old_authorities: &[AuthorityId],
+        new_authorities: &[AuthorityId],
+        session_index: Option<u32>,
+    ) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #(
+            weight = weight.saturating_add(Tuple::on_authorities_changed(
+                old_authorities,
+                new_authorities,
+                session_index,
+            ));
+        )* );
+        weight
+    }
+
+    fn max_weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::max_weight()); )* );
+        weight
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use parity_scale_codec::FullCodec;
@@ -80,7 +155,79 @@ pub mod pallet {
 
         type RelayChainStateProvider: cumulus_pallet_parachain_system::RelaychainStateProvider;
 
-        type AuthorityId: alloc::fmt::Debug + PartialEq + Clone + FullCodec + TypeInfo;
+        type AuthorityId: alloc::fmt::Debug
+            + PartialEq
+            + Clone
+            + FullCodec
+            + TypeInfo
+            + MaxEncodedLen
+            + MaybeSerializeDeserialize;
+
+        /// Maximum number of authorities noted at once. Orchestrator assignments with more
+        /// authorities are truncated.
+        #[pallet::constant]
+        type MaxAuthorities: Get<u32>;
+
+        /// Maximum number of orchestrator collators noted at once. Larger orchestrator collator
+        /// sets are truncated.
+        #[pallet::constant]
+        type MaxOrchestratorAuthorities: Get<u32>;
+
+        /// Number of orchestrator sessions for which the noted authorities are kept in
+        /// `AuthoritiesHistory`. Zero disables the history.
+        #[pallet::constant]
+        type AuthoritiesHistoryDepth: Get<u32>;
+
+        /// What to do when the inherent carries a malformed proof
+        type MalformedProofPolicy: Get<ProofFailurePolicy>;
+
+        /// Number of blocks during which the last noted authorities remain valid after the
+        /// inherent starts failing to note them. Zero clears them on the first failure.
+        #[pallet::constant]
+        type AuthoritiesGracePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Notified when the noted authorities change
+        type OnAuthoritiesChanged: OnAuthoritiesChanged<Self::AuthorityId>;
+
+        /// Finds the author of the current block, to record block production statistics
+        type FindAuthor: FindAuthor<Self::AuthorityId>;
+
+        /// Slot of the current block, to record block production statistics
+        type SlotBeacon: nimbus_primitives::SlotBeacon;
+
+        /// Decides which of the noted authorities can author each slot. [`RoundRobin`] keeps
+        /// the original `authorities[slot % len]` behaviour.
+        type AuthorSelectionStrategy: AuthorSelectionStrategy<Self::AuthorityId>;
+
+        /// Account type of the orchestrator, used for the manager of this chain in the registrar
+        type ManagerAccountId: Parameter + MaxEncodedLen;
+
+        /// Balance type of the orchestrator, used for the registration deposit of this chain
+        type RegistrarBalance: Parameter + MaxEncodedLen;
+
+        /// Validator id of the orchestrator session pallet, which owns the session keys of each
+        /// authority
+        type OrchestratorValidatorId: Parameter + MaxEncodedLen;
+
+        /// Session keys of the orchestrator, noted for each authority in `AuthoritySessionKeys`
+        /// when the inherent proves them
+        type OrchestratorSessionKeys: Parameter + MaxEncodedLen;
+
+        /// Whether the orchestrator session keys of the authorities are noted
+        type NoteAuthoritySessionKeys: Get<bool>;
+
+        /// Time of the current block, checked against the relay slot proved by the inherent
+        type UnixTime: UnixTime;
+
+        /// Duration of a relay chain slot, in milliseconds
+        type RelaySlotDuration: Get<u64>;
+
+        /// Maximum difference, in milliseconds, between the time of a block and the start of the
+        /// relay slot proved by its inherent. It must cover how far behind the relay parent can
+        /// be. `None` disables the check, otherwise blocks whose relay chain proof is valid but
+        /// does not prove the relay slot are invalid. Blocks whose relay chain proof is rejected
+        /// are left to `MalformedProofPolicy`, and are not checked.
+        type MaxRelayTimeDrift: Get<Option<u64>>;
 
         #[cfg(feature = "runtime-benchmarks")]
         type BenchmarkHelper: BenchmarkHelper<Self::AuthorityId>;
@@ -94,17 +241,40 @@ pub mod pallet {
         FailedReading,
         FailedDecodingHeader,
         NoAuthoritiesFound,
+        /// The relay chain state proof does not match the relay parent storage root
+        InvalidRelayChainStateProof,
+        /// The orchestrator chain state proof does not match the orchestrator storage root
+        InvalidOrchestratorChainStateProof,
+        /// A key required to note the authorities is missing from the proof
+        IncompleteProof,
+        /// The proofs provided by the inherent do not match the configured `ChainMode`
+        ChainModeMismatch,
+        /// The orchestrator session is older than the one of the last noted authorities
+        OrchestratorSessionWentBackwards,
+        /// The orchestrator header is older than the one of the last noted authorities
+        OrchestratorHeaderWentBackwards,
+        /// More authorities than `MaxAuthorities` were provided
+        TooManyAuthorities,
+        /// An authorities override must pin at least one authority for at least one block, and
+        /// can only last until an orchestrator session after the last noted one
+        InvalidAuthoritiesOverride,
+        /// There is no authorities override to clear
+        NoAuthoritiesOverride,
+        /// The same authority was provided more than once
+        DuplicateAuthorities,
     }
 
     #[pallet::pallet]
-    #[pallet::without_storage_info]
+    #[pallet::storage_version(migrations::STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             let mut weight = Weight::zero();
 
+            weight += Self::expire_authorities_override(n);
+
             // We clear this storage item to make sure its always included
             DidSetOrchestratorAuthorityData::<T>::kill();
 
@@ -113,6 +283,16 @@ pub mod pallet {
             // The read onfinalizes
             weight += T::DbWeight::get().reads(1);
 
+            weight += Self::note_missed_slots();
+
+            // Recording the author of this block in on_finalize: the digest, up to 3 reads to
+            // find the author from the slot (override, authorities and last noted origin), the
+            // last noted origin and the statistics of the author
+            weight += T::DbWeight::get().reads_writes(6, 1);
+
+            // Checking the time of this block against the relay slot in on_finalize
+            weight += T::DbWeight::get().reads_writes(2, 1);
+
             weight
         }
 
@@ -121,6 +301,14 @@ pub mod pallet {
                 <DidSetOrchestratorAuthorityData<T>>::exists(),
                 "Orchestrator chain authorities data needs to be present in every block!"
             );
+
+            Self::note_authored_block();
+            Self::check_relay_time_drift();
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state(n)
         }
     }
 
@@ -131,6 +319,9 @@ pub mod pallet {
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub orchestrator_para_id: ParaId,
+        pub chain_mode: Option<OrchestratorChainMode>,
+        /// Authorities of the first blocks, until the inherent notes the orchestrator assignment
+        pub authorities: Vec<T::AuthorityId>,
         #[serde(skip)]
         pub _config: core::marker::PhantomData<T>,
     }
@@ -139,6 +330,8 @@ pub mod pallet {
         fn default() -> Self {
             GenesisConfig {
                 orchestrator_para_id: 1000u32.into(),
+                chain_mode: None,
+                authorities: Vec::new(),
                 _config: Default::default(),
             }
         }
@@ -148,74 +341,99 @@ pub mod pallet {
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             OrchestratorParaId::<T>::put(self.orchestrator_para_id);
+            ChainMode::<T>::set(self.chain_mode);
+
+            let authorities = AuthoritiesOf::<T>::try_from(self.authorities.clone())
+                .expect("Genesis authorities must not be more than MaxAuthorities");
+            Authorities::<T>::put(authorities);
         }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight((T::WeightInfo::set_latest_authorities_data(), DispatchClass::Mandatory))]
+        #[pallet::weight((
+            T::WeightInfo::set_latest_authorities_data(
+                data.relay_chain_state.encoded_size() as u32,
+                data.orchestrator_chain_state.encoded_size() as u32,
+                T::MaxAuthorities::get(),
+            )
+            .saturating_add(Pallet::<T>::max_author_stats_removal_weight())
+            .saturating_add(T::OnAuthoritiesChanged::max_weight()),
+            DispatchClass::Mandatory,
+        ))]
         #[allow(clippy::useless_conversion)]
         pub fn set_latest_authorities_data(
             origin: OriginFor<T>,
             data: ccp_authorities_noting_inherent::ContainerChainAuthoritiesInherentData,
         ) -> DispatchResultWithPostInfo {
-            let total_weight = T::WeightInfo::set_latest_authorities_data();
             ensure_none(origin)?;
+            let relay_proof_size = data.relay_chain_state.encoded_size() as u32;
+            let orchestrator_proof_size = data.orchestrator_chain_state.encoded_size() as u32;
+            let weight_of = |num_authorities: usize| {
+                T::WeightInfo::set_latest_authorities_data(
+                    relay_proof_size,
+                    orchestrator_proof_size,
+                    num_authorities as u32,
+                )
+            };
+            let mut total_weight = weight_of(0);
 
             assert!(
                 !<DidSetOrchestratorAuthorityData<T>>::exists(),
                 "DidSetOrchestratorAuthorityData must be updated only once in a block",
             );
 
-            let ccp_authorities_noting_inherent::ContainerChainAuthoritiesInherentData {
-                relay_chain_state: relay_chain_state_proof,
-                orchestrator_chain_state: orchestrator_chain_state_proof,
-            } = data;
-
-            let relay_storage_root =
-                T::RelayChainStateProvider::current_relay_chain_state().state_root;
-            let relay_chain_state_proof =
-                GenericStateProof::new(relay_storage_root, relay_chain_state_proof)
-                    .expect("Invalid relay chain state proof");
-
-            // This pallet needs to support both solochains like starlight and parachains like
-            // dancebox without any config changes because we want the templates to work on both.
-            // To detect whether we should fetch authorities from the orchestrator state proof or
-            // from the relay state proof, we use an empty orchestrator_chain_state_proof as a
-            // sentinel value to indicate that this container chain is running under a solochain.
-            let authorities = if orchestrator_chain_state_proof.is_empty() {
-                // starlight: need to fetch authorities from relay state proof
-                Self::fetch_authorities_from_proof(
-                    &relay_chain_state_proof,
-                    T::SelfParaId::get(),
-                    true,
-                )
-            } else {
-                // dancebox: need to fetch orchestrator state root from paras->heads, and then fetch
-                // authorities from the orchestrator state proof
-                let para_id = OrchestratorParaId::<T>::get();
-                let orchestrator_root = Self::fetch_orchestrator_header_from_relay_proof(
-                    &relay_chain_state_proof,
-                    para_id,
-                )?;
-
-                let orchestrator_chain_state_proof =
-                    GenericStateProof::new(orchestrator_root, orchestrator_chain_state_proof)
-                        .expect("Invalid orchestrator chain state proof");
-
-                Self::fetch_authorities_from_proof(
-                    &orchestrator_chain_state_proof,
-                    T::SelfParaId::get(),
-                    false,
-                )
-            };
+            let relay_state = T::RelayChainStateProvider::current_relay_chain_state();
 
-            match authorities {
-                Ok(authorities) => Authorities::<T>::put(authorities),
+            match Self::fetch_authorities_from_inherent_data(relay_state.state_root, data) {
+                Ok(NotedAuthorities {
+                    session_index,
+                    authorities,
+                    next_authorities,
+                    orchestrator_authorities,
+                    session_keys,
+                    para_info,
+                    orchestrator_header,
+                }) => {
+                    let authorities = Self::bound_authorities(session_index, authorities);
+                    total_weight = weight_of(authorities.len());
+                    let next_authorities = AuthoritiesOf::<T>::truncate_from(next_authorities);
+                    total_weight = total_weight.saturating_add(Self::note_authorities_history(
+                        session_index,
+                        &authorities,
+                    ));
+                    total_weight = total_weight.saturating_add(Self::notify_authorities_changed(
+                        session_index,
+                        &authorities,
+                    ));
+                    Self::note_authority_session_keys(&authorities, session_keys);
+                    GracePeriodExpiresAt::<T>::kill();
+                    Authorities::<T>::put(authorities);
+                    NextAuthorities::<T>::put(next_authorities);
+                    OrchestratorAuthorities::<T>::put(Self::bound_orchestrator_authorities(
+                        orchestrator_authorities,
+                    ));
+                    if let Some(para_info) = para_info {
+                        Self::note_registrar_para_info(para_info);
+                    }
+                    LastNotedOrigin::<T>::put(NotedOrigin {
+                        orchestrator_session_index: session_index,
+                        orchestrator_block_number: orchestrator_header
+                            .as_ref()
+                            .map(|header| header.number),
+                        orchestrator_block_hash: orchestrator_header
+                            .as_ref()
+                            .map(|header| header.hash()),
+                        relay_parent_number: relay_state.number,
+                        relay_parent_storage_root: relay_state.state_root,
+                    });
+                    total_weight = total_weight.saturating_add(
+                        Self::expire_authorities_override_at_session(session_index),
+                    );
+                }
                 Err(e) => {
-                    log::warn!("Authorities-noting error {:?}", e);
-                    Authorities::<T>::kill();
+                    total_weight = total_weight.saturating_add(Self::handle_noting_failure(e))
                 }
             }
 
@@ -228,14 +446,30 @@ pub mod pallet {
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::set_authorities(authorities.len() as u32))]
+        #[pallet::weight(
+            T::WeightInfo::set_authorities(authorities.len() as u32)
+                .saturating_add(T::OnAuthoritiesChanged::max_weight())
+        )]
         pub fn set_authorities(
             origin: OriginFor<T>,
             authorities: Vec<T::AuthorityId>,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            let authorities = Self::bound_unique_authorities(authorities)?;
+            let old_authorities = Authorities::<T>::get();
+            // The authorities in use do not change while they are overridden, the hook is called
+            // when the override ends instead
+            if old_authorities != authorities && !AuthoritiesOverride::<T>::exists() {
+                let _ = T::OnAuthoritiesChanged::on_authorities_changed(
+                    &old_authorities,
+                    &authorities,
+                    Self::last_noted_session(),
+                );
+            }
             Authorities::<T>::put(&authorities);
-            Self::deposit_event(Event::AuthoritiesInserted { authorities });
+            Self::deposit_event(Event::AuthoritiesInserted {
+                authorities: authorities.into_inner(),
+            });
             Ok(())
         }
 
@@ -247,9 +481,96 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
             OrchestratorParaId::<T>::put(new_para_id);
+            // Block numbers of the new orchestrator are not comparable with the noted ones
+            LastNotedOrigin::<T>::kill();
+            OrchestratorAuthorities::<T>::kill();
             Self::deposit_event(Event::OrchestratorParachainIdUpdated { new_para_id });
             Ok(())
         }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_chain_mode())]
+        pub fn set_chain_mode(
+            origin: OriginFor<T>,
+            new_mode: Option<OrchestratorChainMode>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ChainMode::<T>::set(new_mode);
+            // Sessions read from the relay chain are not comparable with orchestrator sessions
+            LastNotedOrigin::<T>::kill();
+            Self::deposit_event(Event::ChainModeUpdated { new_mode });
+            Ok(())
+        }
+
+        /// Pin `authorities` for `duration` blocks, or until the orchestrator session
+        /// `until_session` is noted if that happens first. The inherent keeps noting the
+        /// orchestrator authorities in the meantime, and they are used again once the override
+        /// expires.
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            T::WeightInfo::set_authorities_override(authorities.len() as u32)
+                .saturating_add(T::OnAuthoritiesChanged::max_weight())
+        )]
+        pub fn set_authorities_override(
+            origin: OriginFor<T>,
+            authorities: Vec<T::AuthorityId>,
+            duration: BlockNumberFor<T>,
+            until_session: Option<u32>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                !authorities.is_empty() && !duration.is_zero(),
+                Error::<T>::InvalidAuthoritiesOverride
+            );
+            if let (Some(until_session), Some(last_session)) =
+                (until_session, Self::last_noted_session())
+            {
+                ensure!(
+                    until_session > last_session,
+                    Error::<T>::InvalidAuthoritiesOverride
+                );
+            }
+            let authorities = Self::bound_unique_authorities(authorities)?;
+
+            let old_authorities = Self::effective_authorities();
+            if old_authorities != authorities {
+                let _ = T::OnAuthoritiesChanged::on_authorities_changed(
+                    &old_authorities,
+                    &authorities,
+                    Self::last_noted_session(),
+                );
+            }
+
+            let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+            AuthoritiesOverride::<T>::put(PinnedAuthorities {
+                authorities: authorities.clone(),
+                expires_at,
+                until_session,
+            });
+            Self::deposit_event(Event::AuthoritiesOverrideStarted {
+                authorities: authorities.into_inner(),
+                expires_at,
+                until_session,
+            });
+            Ok(())
+        }
+
+        /// Remove the authorities override before it expires
+        #[pallet::call_index(5)]
+        #[pallet::weight(
+            T::WeightInfo::clear_authorities_override()
+                .saturating_add(T::OnAuthoritiesChanged::max_weight())
+        )]
+        pub fn clear_authorities_override(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                AuthoritiesOverride::<T>::exists(),
+                Error::<T>::NoAuthoritiesOverride
+            );
+            let _ = Self::end_authorities_override();
+            Self::deposit_event(Event::AuthoritiesOverrideCleared);
+            Ok(())
+        }
     }
 
     #[pallet::event]
@@ -259,11 +580,148 @@ pub mod pallet {
         AuthoritiesInserted { authorities: Vec<T::AuthorityId> },
         /// Orchestrator Parachain Id updated
         OrchestratorParachainIdUpdated { new_para_id: ParaId },
+        /// Chain mode updated
+        ChainModeUpdated {
+            new_mode: Option<OrchestratorChainMode>,
+        },
+        /// The inherent could not note the authorities
+        AuthoritiesNotingFailed { error: DispatchError },
+        /// The inherent noted a set of authorities different from the previous one
+        AuthoritiesChanged {
+            old_session: Option<u32>,
+            new_session: u32,
+            added: Vec<T::AuthorityId>,
+            removed: Vec<T::AuthorityId>,
+        },
+        /// The orchestrator assigned more than `MaxAuthorities` authorities, only the first
+        /// `MaxAuthorities` of them were noted
+        AuthoritiesTruncated { session_index: u32, assigned: u32 },
+        /// Noting failed, the previous authorities are kept until `expires_at`
+        GracePeriodStarted { expires_at: BlockNumberFor<T> },
+        /// Noting kept failing until the end of the grace period, the authorities were cleared
+        GracePeriodExpired,
+        /// The registration of this chain proved by the inherent changed. `None` if it is not
+        /// registered anymore.
+        RegistrarParaInfoUpdated { para_info: Option<ParaInfoOf<T>> },
+        /// The orchestrator paused or deregistered this chain, non-inherent extrinsics are
+        /// filtered until it is enabled again
+        ChainHalted { reason: HaltReason },
+        /// The orchestrator enabled this chain again after it was halted
+        ChainResumed,
+        /// The randomness of a new relay chain epoch was noted
+        RelayRandomnessUpdated { epoch_index: u64 },
+        /// `authorities` are pinned until block `expires_at`, or until the orchestrator session
+        /// `until_session` is noted
+        AuthoritiesOverrideStarted {
+            authorities: Vec<T::AuthorityId>,
+            expires_at: BlockNumberFor<T>,
+            until_session: Option<u32>,
+        },
+        /// The authorities override expired, the noted authorities are used again
+        AuthoritiesOverrideExpired,
+        /// The authorities override was cleared before it expired
+        AuthoritiesOverrideCleared,
     }
 
+    /// Whether the orchestrator runs as a parachain or as a solochain. If `None`, the mode is
+    /// inferred from the inherent: an empty orchestrator chain state proof means solochain.
+    #[pallet::storage]
+    #[pallet::getter(fn chain_mode)]
+    pub type ChainMode<T: Config> = StorageValue<_, OrchestratorChainMode, OptionQuery>;
+
+    #[pallet::storage]
+    pub type Authorities<T: Config> = StorageValue<_, AuthoritiesOf<T>, ValueQuery>;
+
+    /// Block at which the grace period started by a failed noting ends. Removed as soon as the
+    /// authorities are noted again.
+    #[pallet::storage]
+    #[pallet::getter(fn grace_period_expires_at)]
+    pub type GracePeriodExpiresAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Authorities assigned to this container chain in the next orchestrator session. Empty if
+    /// the next assignment is not known yet.
+    #[pallet::storage]
+    pub type NextAuthorities<T: Config> = StorageValue<_, AuthoritiesOf<T>, ValueQuery>;
+
+    /// Collators assigned to the orchestrator chain itself in the last noted orchestrator
+    /// session
+    #[pallet::storage]
+    pub type OrchestratorAuthorities<T: Config> =
+        StorageValue<_, BoundedVec<T::AuthorityId, T::MaxOrchestratorAuthorities>, ValueQuery>;
+
+    /// Orchestrator session keys of the noted authorities, for those whose keys were proved by
+    /// the inherent
+    #[pallet::storage]
+    #[pallet::getter(fn authority_session_keys)]
+    pub type AuthoritySessionKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AuthorityId, T::OrchestratorSessionKeys, OptionQuery>;
+
+    /// Authorities pinned by `set_authorities_override`, used instead of the noted ones until
+    /// the override expires
+    #[pallet::storage]
+    #[pallet::getter(fn authorities_override)]
+    pub type AuthoritiesOverride<T: Config> = StorageValue<_, PinnedAuthoritiesOf<T>, OptionQuery>;
+
+    /// Registration of this chain in the relay chain registrar, if the inherent proved it. `None`
+    /// if this chain is not registered there.
+    #[pallet::storage]
+    #[pallet::getter(fn registrar_para_info)]
+    pub type RegistrarParaInfo<T: Config> = StorageValue<_, ParaInfoOf<T>, OptionQuery>;
+
+    /// Why this chain is halted, as proved by the inherent. `None` while the orchestrator has
+    /// this chain registered and not paused, or if its status was never proved.
+    #[pallet::storage]
+    #[pallet::getter(fn halted)]
+    pub type Halted<T: Config> = StorageValue<_, HaltReason, OptionQuery>;
+
+    /// Relay chain slot proved by the inherent of this block, removed in `on_finalize`
     #[pallet::storage]
-    #[pallet::getter(fn authorities)]
-    pub type Authorities<T: Config> = StorageValue<_, Vec<T::AuthorityId>, ValueQuery>;
+    pub type RelaySlot<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Randomness of the last relay chain epoch proved by the inherent, used by
+    /// [`RelayRandomness`]
+    #[pallet::storage]
+    #[pallet::getter(fn relay_epoch_randomness)]
+    pub type RelayEpochRandomness<T: Config> =
+        StorageValue<_, NotedRandomness<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Authorities noted through the inherent for each of the last `AuthoritiesHistoryDepth`
+    /// orchestrator sessions
+    #[pallet::storage]
+    pub type AuthoritiesHistory<T: Config> =
+        StorageMap<_, Twox64Concat, u32, AuthoritiesOf<T>, OptionQuery>;
+
+    /// Orchestrator sessions present in `AuthoritiesHistory`, oldest first, together with the
+    /// first container block in which the authorities of that session were noted
+    #[pallet::storage]
+    pub type AuthoritiesHistorySessions<T: Config> = StorageValue<
+        _,
+        BoundedVec<(u32, BlockNumberFor<T>), T::AuthoritiesHistoryDepth>,
+        ValueQuery,
+    >;
+
+    /// Provenance of the authorities noted by the last successful inherent
+    #[pallet::storage]
+    #[pallet::getter(fn last_noted_origin)]
+    pub type LastNotedOrigin<T: Config> = StorageValue<_, NotedOrigin, OptionQuery>;
+
+    /// Blocks authored and slots missed by each author, per orchestrator session. Kept for the
+    /// sessions in `AuthoritiesHistory`, or only for the last noted session if the history is
+    /// disabled.
+    #[pallet::storage]
+    pub type AuthorStats<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AuthorityId,
+        BlockProductionStats,
+        ValueQuery,
+    >;
+
+    /// Slot of the last block, to find the slots missed since then
+    #[pallet::storage]
+    pub type LastBlockSlot<T: Config> = StorageValue<_, u32, OptionQuery>;
 
     /// Was the containerAuthorData set?
     #[pallet::storage]
@@ -299,12 +757,274 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
-    /// Fetch author slot from a proof of header
+    /// Verify the proofs of the inherent and read the authorities assigned to this chain
+    fn fetch_authorities_from_inherent_data(
+        relay_storage_root: H256,
+        data: ccp_authorities_noting_inherent::ContainerChainAuthoritiesInherentData,
+    ) -> Result<NotedAuthorities<T>, Error<T>> {
+        let ccp_authorities_noting_inherent::ContainerChainAuthoritiesInherentData {
+            relay_chain_state: relay_chain_state_proof,
+            orchestrator_chain_state: orchestrator_chain_state_proof,
+        } = data;
+
+        let relay_chain_state_proof =
+            GenericStateProof::new(relay_storage_root, relay_chain_state_proof)
+                .map_err(|_| Error::<T>::InvalidRelayChainStateProof)?;
+
+        // The relay slot and randomness do not depend on the orchestrator, so they are noted even
+        // if the authorities cannot be
+        Self::note_relay_slot(&relay_chain_state_proof);
+        Self::note_relay_randomness(&relay_chain_state_proof);
+
+        // This pallet needs to support both solochains like starlight and parachains like
+        // dancebox without any config changes because we want the templates to work on both.
+        // To detect whether we should fetch authorities from the orchestrator state proof or
+        // from the relay state proof, we use an empty orchestrator_chain_state_proof as a
+        // sentinel value to indicate that this container chain is running under a solochain.
+        // If the mode is set explicitly, the sentinel must agree with it.
+        let solochain = orchestrator_chain_state_proof.is_empty();
+        if let Some(mode) = ChainMode::<T>::get() {
+            ensure!(
+                solochain == (mode == OrchestratorChainMode::Solochain),
+                Error::<T>::ChainModeMismatch
+            );
+        }
+
+        let noted = if solochain {
+            // The halt status is noted before the authorities because a paused or deregistered
+            // chain has no authorities assigned, and reading them fails
+            Self::note_halt_reason(&relay_chain_state_proof, T::SelfParaId::get(), true);
+
+            // starlight: need to fetch authorities from relay state proof
+            Self::fetch_authorities_from_proof(
+                &relay_chain_state_proof,
+                T::SelfParaId::get(),
+                true,
+            )?
+        } else {
+            // dancebox: need to fetch orchestrator state root from paras->heads, and then fetch
+            // authorities from the orchestrator state proof
+            let para_id = OrchestratorParaId::<T>::get();
+            let orchestrator_header = Self::fetch_orchestrator_header_from_relay_proof(
+                &relay_chain_state_proof,
+                para_id,
+            )?;
+            Self::ensure_header_not_older_than_last_noted(&orchestrator_header)?;
+
+            let orchestrator_chain_state_proof = GenericStateProof::new(
+                orchestrator_header.state_root,
+                orchestrator_chain_state_proof,
+            )
+            .map_err(|_| Error::<T>::InvalidOrchestratorChainStateProof)?;
+
+            // Same as above, the halt status is noted even if this chain has no authorities
+            Self::note_halt_reason(&orchestrator_chain_state_proof, T::SelfParaId::get(), false);
+
+            let noted = Self::fetch_authorities_from_proof(
+                &orchestrator_chain_state_proof,
+                T::SelfParaId::get(),
+                false,
+            )?;
+
+            NotedAuthorities {
+                orchestrator_header: Some(orchestrator_header),
+                ..noted
+            }
+        };
+
+        Self::ensure_not_older_than_last_noted(&noted)?;
+
+        // The registration of this chain is kept by the relay chain registrar in both modes
+        Ok(NotedAuthorities {
+            para_info: Self::read_registrar_para_info(&relay_chain_state_proof),
+            ..noted
+        })
+    }
+
+    /// Refuse authorities read from an orchestrator session older than the last noted one, so
+    /// that replayed relay chain state cannot roll the authorities back
+    fn ensure_not_older_than_last_noted(noted: &NotedAuthorities<T>) -> Result<(), Error<T>> {
+        let Some(last_noted) = LastNotedOrigin::<T>::get() else {
+            return Ok(());
+        };
+
+        // The session indexes of a solochain and a parachain orchestrator are not comparable,
+        // which happens if the mode changed since the last noting
+        if noted.orchestrator_header.is_some() != last_noted.orchestrator_block_number.is_some() {
+            return Ok(());
+        }
+
+        ensure!(
+            noted.session_index >= last_noted.orchestrator_session_index,
+            Error::<T>::OrchestratorSessionWentBackwards
+        );
+
+        Ok(())
+    }
+
+    /// Refuse an orchestrator header older than the one the last authorities were read from,
+    /// before anything is read from its state
+    fn ensure_header_not_older_than_last_noted(
+        header: &OrchestratorHeader,
+    ) -> Result<(), Error<T>> {
+        if let Some(last_number) =
+            LastNotedOrigin::<T>::get().and_then(|last_noted| last_noted.orchestrator_block_number)
+        {
+            ensure!(
+                header.number >= last_number,
+                Error::<T>::OrchestratorHeaderWentBackwards
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Deposit `AuthoritiesChanged` and call `OnAuthoritiesChanged` if `authorities` differ from
+    /// the currently noted ones. Must be called before `Authorities` and `LastNotedOrigin` are
+    /// updated. Returns the weight of the hook.
+    fn notify_authorities_changed(new_session: u32, authorities: &[T::AuthorityId]) -> Weight {
+        let old_authorities = Authorities::<T>::get();
+        if old_authorities.as_slice() == authorities {
+            return Weight::zero();
+        }
+
+        // The authorities in use do not change while they are overridden, the hook is called
+        // when the override ends instead
+        let weight = if !AuthoritiesOverride::<T>::exists() {
+            T::OnAuthoritiesChanged::on_authorities_changed(
+                &old_authorities,
+                authorities,
+                Some(new_session),
+            )
+        } else {
+            Weight::zero()
+        };
+
+        let added = authorities
+            .iter()
+            .filter(|authority| !old_authorities.contains(authority))
+            .cloned()
+            .collect();
+        let removed = old_authorities
+            .iter()
+            .filter(|authority| !authorities.contains(authority))
+            .cloned()
+            .collect();
+
+        Self::deposit_event(Event::AuthoritiesChanged {
+            old_session: Self::last_noted_session(),
+            new_session,
+            added,
+            removed,
+        });
+
+        weight
+    }
+
+    /// Apply the configured policy after the inherent failed to note the authorities. Returns
+    /// the weight of `OnAuthoritiesChanged` if the authorities were cleared.
+    fn handle_noting_failure(error: Error<T>) -> Weight {
+        log::warn!("Authorities-noting error {:?}", error);
+
+        let is_malformed_proof = matches!(
+            error,
+            Error::<T>::InvalidRelayChainStateProof
+                | Error::<T>::InvalidOrchestratorChainStateProof
+                | Error::<T>::IncompleteProof
+        );
+        let went_backwards = matches!(
+            error,
+            Error::<T>::OrchestratorSessionWentBackwards
+                | Error::<T>::OrchestratorHeaderWentBackwards
+        );
+        let clear_authorities = if went_backwards {
+            // The authorities noted before are newer than the rejected ones
+            false
+        } else if is_malformed_proof {
+            match T::MalformedProofPolicy::get() {
+                ProofFailurePolicy::Panic => {
+                    panic!("Invalid authorities noting proof: {:?}", error)
+                }
+                ProofFailurePolicy::KeepPrevious => false,
+                ProofFailurePolicy::Clear => true,
+            }
+        } else {
+            true
+        };
+
+        Self::deposit_event(Event::AuthoritiesNotingFailed {
+            error: error.into(),
+        });
+
+        if clear_authorities {
+            Self::clear_authorities_after_grace_period()
+        } else {
+            Weight::zero()
+        }
+    }
+
+    /// Clear the noted authorities, unless they are still within the grace period. Returns the
+    /// weight of `OnAuthoritiesChanged` if they were cleared.
+    fn clear_authorities_after_grace_period() -> Weight {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        match GracePeriodExpiresAt::<T>::get() {
+            None => {
+                let grace_period = T::AuthoritiesGracePeriod::get();
+                if grace_period.is_zero() || Authorities::<T>::get().is_empty() {
+                    Self::clear_authorities()
+                } else {
+                    let expires_at = now.saturating_add(grace_period);
+                    GracePeriodExpiresAt::<T>::put(expires_at);
+                    Self::deposit_event(Event::GracePeriodStarted { expires_at });
+                    Weight::zero()
+                }
+            }
+            Some(expires_at) if now >= expires_at => {
+                GracePeriodExpiresAt::<T>::kill();
+                let weight = Self::clear_authorities();
+                Self::deposit_event(Event::GracePeriodExpired);
+                weight
+            }
+            Some(_) => Weight::zero(),
+        }
+    }
+
+    /// Remove the noted authorities, depositing `AuthoritiesChanged` and calling
+    /// `OnAuthoritiesChanged` if there were any. Returns the weight of the hook.
+    fn clear_authorities() -> Weight {
+        let old_authorities = Authorities::<T>::take();
+        NextAuthorities::<T>::kill();
+        OrchestratorAuthorities::<T>::kill();
+        let _ = AuthoritySessionKeys::<T>::clear(T::MaxAuthorities::get(), None);
+
+        if old_authorities.is_empty() {
+            return Weight::zero();
+        }
+
+        // No new session was noted, the authorities are removed within the last noted one
+        let old_session = Self::last_noted_session();
+        let weight = if !AuthoritiesOverride::<T>::exists() {
+            T::OnAuthoritiesChanged::on_authorities_changed(&old_authorities, &[], old_session)
+        } else {
+            Weight::zero()
+        };
+        Self::deposit_event(Event::AuthoritiesChanged {
+            old_session,
+            new_session: old_session.unwrap_or_default(),
+            added: Vec::new(),
+            removed: old_authorities.into_inner(),
+        });
+
+        weight
+    }
+
+    /// Fetch the orchestrator header from the relay proof of its para head
     /// TODO: fix me once we have a proper Block type
     fn fetch_orchestrator_header_from_relay_proof(
         relay_state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
         para_id: ParaId,
-    ) -> Result<<BlakeTwo256 as HashT>::Output, Error<T>> {
+    ) -> Result<OrchestratorHeader, Error<T>> {
         let bytes = para_id.twox_64_concat();
         // CONCAT
         let key = [well_known_keys::PARAS_HEADS_INDEX, bytes.as_slice()].concat();
@@ -313,38 +1033,32 @@ impl<T: Config> Pallet<T> {
         // In this process several errors can occur, but we will only log if such errors happen
         // We first take the HeadData
         // If the readError was that the key was not provided (identified by the Proof error),
-        // then panic
+        // then the proof is incomplete
         let head_data = relay_state_proof
             .read_entry::<HeadData>(key.as_slice(), None)
             .map_err(|e| match e {
-                ReadEntryErr::Proof => panic!("Invalid proof provided for para head key"),
+                ReadEntryErr::Proof => Error::<T>::IncompleteProof,
                 _ => Error::<T>::FailedReading,
             })?;
 
         // We later take the Header decoded
-        let orchestrator_chain_header =
-            sp_runtime::generic::Header::<BlockNumber, BlakeTwo256>::decode(
-                &mut head_data.0.as_slice(),
-            )
+        let orchestrator_chain_header = OrchestratorHeader::decode(&mut head_data.0.as_slice())
             .map_err(|_| Error::<T>::FailedDecodingHeader)?;
 
-        // Fetch the orchestrator chain storage root
-        let orchestrator_chain_storage_root = orchestrator_chain_header.state_root;
-
-        Ok(orchestrator_chain_storage_root)
+        Ok(orchestrator_chain_header)
     }
 
-    /// Fetch author slot from a proof of header
+    /// Fetch the authorities assigned to `para_id` for the current and the next session
     fn fetch_authorities_from_proof(
         state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
         para_id: ParaId,
         solochain: bool,
-    ) -> Result<Vec<T::AuthorityId>, Error<T>> {
+    ) -> Result<NotedAuthorities<T>, Error<T>> {
         // Read orchestrator session index
         let session_index = state_proof
             .read_entry::<u32>(well_known_keys::SESSION_INDEX, None)
             .map_err(|e| match e {
-                ReadEntryErr::Proof => panic!("Invalid proof: cannot read session index"),
+                ReadEntryErr::Proof => Error::<T>::IncompleteProof,
                 _ => Error::<T>::FailedReading,
             })?;
         let pallet_authorities_prefix = if solochain {
@@ -363,19 +1077,733 @@ impl<T: Config> Pallet<T> {
                 None,
             )
             .map_err(|e| match e {
-                ReadEntryErr::Proof => panic!("Invalid proof: cannot read assignment"),
+                ReadEntryErr::Proof => Error::<T>::IncompleteProof,
                 _ => Error::<T>::FailedReading,
             })?;
 
         // Read those authorities assigned to this chain
-        let authorities = assignment
-            .container_chains
-            .get(&para_id)
+        let AssignedCollators {
+            orchestrator_chain: orchestrator_authorities,
+            mut container_chains,
+        } = assignment;
+        let authorities = container_chains
+            .remove(&para_id)
             .ok_or(Error::<T>::NoAuthoritiesFound)?;
-        Ok(authorities.clone())
+
+        // The assignment for the next session may not be part of the proof (collators running an
+        // older client do not include it), so we never fail because of it
+        let next_assignment = state_proof
+            .read_optional_entry::<AssignedCollators<T::AuthorityId>>(
+                &well_known_keys::authority_assignment_for_session(
+                    session_index.saturating_add(1),
+                    pallet_authorities_prefix,
+                ),
+            )
+            .unwrap_or_else(|e| {
+                log::debug!("Cannot read next session assignment {:?}", e);
+                None
+            });
+        let next_authorities = next_assignment
+            .and_then(|mut assignment| assignment.container_chains.remove(&para_id))
+            .unwrap_or_default();
+
+        let session_keys = if !T::NoteAuthoritySessionKeys::get() {
+            Vec::new()
+        } else {
+            authorities
+                .iter()
+                .filter_map(|authority| {
+                    Some((
+                        authority.clone(),
+                        Self::read_authority_session_keys(state_proof, authority)?,
+                    ))
+                })
+                .collect()
+        };
+
+        Ok(NotedAuthorities {
+            session_index,
+            authorities,
+            next_authorities,
+            orchestrator_authorities,
+            session_keys,
+            para_info: None,
+            orchestrator_header: None,
+        })
+    }
+
+    /// Read the registration of this chain in the relay chain registrar. `None` if it is not
+    /// part of the proof, which is the case for collators running an older client.
+    fn read_registrar_para_info(
+        relay_state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
+    ) -> Option<Option<ParaInfoOf<T>>> {
+        relay_state_proof
+            .read_optional_entry::<ParaInfoOf<T>>(&well_known_keys::registrar_para_info(
+                T::SelfParaId::get(),
+            ))
+            .map_err(|e| log::debug!("Cannot read registrar para info {:?}", e))
+            .ok()
+    }
+
+    /// Read the orchestrator session keys of `authority`, through the validator that owns its
+    /// nimbus key. `None` if they are not part of the proof, which is the case for collators
+    /// running an older client.
+    fn read_authority_session_keys(
+        state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
+        authority: &T::AuthorityId,
+    ) -> Option<T::OrchestratorSessionKeys> {
+        // The raw public key of a nimbus id is its encoding
+        let owner = state_proof
+            .read_entry::<T::OrchestratorValidatorId>(
+                &well_known_keys::session_key_owner(NIMBUS_KEY_ID, &authority.encode()),
+                None,
+            )
+            .ok()?;
+
+        state_proof
+            .read_entry::<T::OrchestratorSessionKeys>(
+                &well_known_keys::session_next_keys(&owner.encode()),
+                None,
+            )
+            .ok()
+    }
+
+    /// Store the session keys proved for the noted `authorities`, and remove the ones of the
+    /// authorities that are not noted anymore. Must be called before `Authorities` is updated.
+    fn note_authority_session_keys(
+        authorities: &AuthoritiesOf<T>,
+        session_keys: Vec<(T::AuthorityId, T::OrchestratorSessionKeys)>,
+    ) {
+        for old_authority in Authorities::<T>::get() {
+            if !authorities.contains(&old_authority) {
+                AuthoritySessionKeys::<T>::remove(old_authority);
+            }
+        }
+
+        for (authority, keys) in session_keys {
+            if authorities.contains(&authority)
+                && AuthoritySessionKeys::<T>::get(&authority).as_ref() != Some(&keys)
+            {
+                AuthoritySessionKeys::<T>::insert(authority, keys);
+            }
+        }
+    }
+
+    /// Truncate the authorities assigned by the orchestrator to `MaxAuthorities`
+    fn bound_authorities(session_index: u32, authorities: Vec<T::AuthorityId>) -> AuthoritiesOf<T> {
+        let assigned = authorities.len() as u32;
+        if assigned > T::MaxAuthorities::get() {
+            log::warn!(
+                "Orchestrator assigned {} authorities, only the first {} are noted",
+                assigned,
+                T::MaxAuthorities::get()
+            );
+            Self::deposit_event(Event::AuthoritiesTruncated {
+                session_index,
+                assigned,
+            });
+        }
+
+        AuthoritiesOf::<T>::truncate_from(authorities)
+    }
+
+    /// Bound authorities provided by root, which must not contain duplicates
+    fn bound_unique_authorities(
+        authorities: Vec<T::AuthorityId>,
+    ) -> Result<AuthoritiesOf<T>, Error<T>> {
+        let authorities = AuthoritiesOf::<T>::try_from(authorities)
+            .map_err(|_| Error::<T>::TooManyAuthorities)?;
+        for (i, authority) in authorities.iter().enumerate() {
+            ensure!(
+                !authorities[i + 1..].contains(authority),
+                Error::<T>::DuplicateAuthorities
+            );
+        }
+
+        Ok(authorities)
+    }
+
+    /// Truncate the orchestrator collators to `MaxOrchestratorAuthorities`
+    fn bound_orchestrator_authorities(
+        authorities: Vec<T::AuthorityId>,
+    ) -> BoundedVec<T::AuthorityId, T::MaxOrchestratorAuthorities> {
+        if authorities.len() as u32 > T::MaxOrchestratorAuthorities::get() {
+            log::warn!(
+                "Orchestrator has {} collators, only the first {} are noted",
+                authorities.len(),
+                T::MaxOrchestratorAuthorities::get()
+            );
+        }
+
+        BoundedVec::truncate_from(authorities)
+    }
+
+    /// Read whether this chain is paused or not registered in the orchestrator. `None` if the
+    /// registrar state is not part of the proof.
+    fn read_halt_reason(
+        state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
+        para_id: ParaId,
+        solochain: bool,
+    ) -> Option<Option<HaltReason>> {
+        let (registered_key, paused_key) = if solochain {
+            (
+                well_known_keys::SOLOCHAIN_REGISTERED_PARA_IDS,
+                well_known_keys::SOLOCHAIN_PAUSED_PARA_IDS,
+            )
+        } else {
+            (
+                well_known_keys::REGISTERED_PARA_IDS,
+                well_known_keys::PAUSED_PARA_IDS,
+            )
+        };
+
+        // Collators running an older client do not include the registrar state in the proof
+        let read_para_ids = |key: &[u8]| {
+            state_proof
+                .read_optional_entry::<Vec<ParaId>>(key)
+                .map(Option::unwrap_or_default)
+                .map_err(|e| log::debug!("Cannot read registrar para ids {:?}", e))
+                .ok()
+        };
+        let registered = read_para_ids(registered_key)?;
+        let paused = read_para_ids(paused_key)?;
+
+        if paused.contains(&para_id) {
+            Some(Some(HaltReason::Paused))
+        } else if !registered.contains(&para_id) {
+            Some(Some(HaltReason::NotRegistered))
+        } else {
+            Some(None)
+        }
+    }
+
+    /// Halt or resume this chain according to its registrar state in `state_proof`, keeping the
+    /// current status if it is not part of the proof
+    fn note_halt_reason(
+        state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
+        para_id: ParaId,
+        solochain: bool,
+    ) {
+        let Some(reason) = Self::read_halt_reason(state_proof, para_id, solochain) else {
+            return;
+        };
+
+        if Halted::<T>::get() == reason {
+            return;
+        }
+
+        Halted::<T>::set(reason);
+        match reason {
+            Some(reason) => {
+                log::warn!("Chain halted by the orchestrator: {:?}", reason);
+                Self::deposit_event(Event::ChainHalted { reason });
+            }
+            None => Self::deposit_event(Event::ChainResumed),
+        }
+    }
+
+    /// Whether the orchestrator paused or deregistered this chain. Only inherents and calls
+    /// allowed by [`NotHalted`] can be dispatched while halted.
+    pub fn is_halted() -> bool {
+        Halted::<T>::exists()
+    }
+
+    /// Store the relay slot of the proof, for `check_relay_time_drift`. Panics if the slot is
+    /// not part of the proof while the time drift is checked.
+    fn note_relay_slot(
+        relay_state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
+    ) {
+        // Collators running an older client do not include the slot in the proof, which only
+        // makes the block invalid if the time drift is checked
+        match relay_state_proof.read_entry::<u64>(well_known_keys::RELAY_CURRENT_SLOT, None) {
+            Ok(slot) => RelaySlot::<T>::put(slot),
+            Err(e) if T::MaxRelayTimeDrift::get().is_some() => panic!(
+                "Relay slot must be proved while the time drift is checked: {:?}",
+                e
+            ),
+            Err(e) => log::debug!("Cannot read relay slot {:?}", e),
+        }
+    }
+
+    /// Panic if the time of this block is more than `MaxRelayTimeDrift` away from the start of
+    /// the relay slot noted by the inherent. Not checked if the relay chain proof was rejected,
+    /// which is handled by `MalformedProofPolicy` instead.
+    fn check_relay_time_drift() {
+        let Some(relay_slot) = RelaySlot::<T>::take() else {
+            return;
+        };
+        let Some(max_drift) = T::MaxRelayTimeDrift::get() else {
+            return;
+        };
+
+        let relay_time = relay_slot.saturating_mul(T::RelaySlotDuration::get());
+        let now = T::UnixTime::now().as_millis() as u64;
+
+        assert!(
+            now.abs_diff(relay_time) <= max_drift,
+            "Timestamp {} drifts more than {}ms from relay slot {} starting at {}",
+            now,
+            max_drift,
+            relay_slot,
+            relay_time,
+        );
+    }
+
+    /// Store the epoch randomness of the relay chain if it is part of the proof and changed
+    fn note_relay_randomness(
+        relay_state_proof: &GenericStateProof<cumulus_primitives_core::relay_chain::Block>,
+    ) {
+        // Collators running an older client do not include the randomness in the proof
+        let read = || -> Result<_, ReadEntryErr> {
+            let epoch_index =
+                relay_state_proof.read_entry::<u64>(well_known_keys::RELAY_EPOCH_INDEX, None)?;
+            let randomness = relay_state_proof
+                .read_entry::<[u8; 32]>(well_known_keys::RELAY_EPOCH_RANDOMNESS, None)?;
+            Ok((epoch_index, randomness))
+        };
+        let (epoch_index, randomness) = match read() {
+            Ok(read) => read,
+            Err(e) => {
+                log::debug!("Cannot read relay epoch randomness {:?}", e);
+                return;
+            }
+        };
+
+        if let Some(noted) = RelayEpochRandomness::<T>::get() {
+            if epoch_index <= noted.epoch_index {
+                return;
+            }
+        }
+
+        RelayEpochRandomness::<T>::put(NotedRandomness {
+            epoch_index,
+            randomness,
+            noted_at: frame_system::Pallet::<T>::block_number(),
+        });
+        Self::deposit_event(Event::RelayRandomnessUpdated { epoch_index });
+    }
+
+    /// Store the proved registration of this chain, emitting an event if it changed
+    fn note_registrar_para_info(para_info: Option<ParaInfoOf<T>>) {
+        if RegistrarParaInfo::<T>::get() != para_info {
+            RegistrarParaInfo::<T>::set(para_info.clone());
+            Self::deposit_event(Event::RegistrarParaInfoUpdated { para_info });
+        }
+    }
+
+    /// Store the authorities noted for `session_index` in the history, pruning the oldest
+    /// session if the history is full. Returns the weight of removing the statistics of the
+    /// pruned session.
+    fn note_authorities_history(session_index: u32, authorities: &AuthoritiesOf<T>) -> Weight {
+        if T::AuthoritiesHistoryDepth::get() == 0 {
+            // Only the statistics of the current session are kept
+            return match Self::last_noted_session() {
+                Some(last_session) if last_session != session_index => {
+                    Self::clear_author_stats(last_session)
+                }
+                _ => Weight::zero(),
+            };
+        }
+
+        let mut weight = Weight::zero();
+        let mut sessions = AuthoritiesHistorySessions::<T>::get();
+        if sessions.last().map(|(session, _)| *session) != Some(session_index) {
+            if sessions.is_full() {
+                let (oldest_session, _) = sessions.remove(0);
+                AuthoritiesHistory::<T>::remove(oldest_session);
+                weight = Self::clear_author_stats(oldest_session);
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            // Cannot fail, there is room for at least one more session
+            let _ = sessions.try_push((session_index, now));
+            AuthoritiesHistorySessions::<T>::put(sessions);
+            AuthoritiesHistory::<T>::insert(session_index, authorities);
+        } else if AuthoritiesHistory::<T>::get(session_index).as_ref() != Some(authorities) {
+            AuthoritiesHistory::<T>::insert(session_index, authorities);
+        }
+
+        weight
+    }
+
+    /// Remove the block production statistics of `session_index`. At most `MaxAuthorities`
+    /// entries are removed, which are all of them unless the authorities were replaced within
+    /// the session.
+    fn clear_author_stats(session_index: u32) -> Weight {
+        let removal = AuthorStats::<T>::clear_prefix(session_index, T::MaxAuthorities::get(), None);
+        if removal.maybe_cursor.is_some() {
+            log::warn!(
+                "Statistics of more than {} authors in session {}, some are kept",
+                T::MaxAuthorities::get(),
+                session_index
+            );
+        }
+
+        T::DbWeight::get().reads_writes(removal.loops.into(), removal.unique.into())
+    }
+
+    /// Weight of removing the statistics of a session in the worst case
+    fn max_author_stats_removal_weight() -> Weight {
+        let max_authorities = T::MaxAuthorities::get().into();
+        T::DbWeight::get().reads_writes(max_authorities, max_authorities)
+    }
+
+    /// Authorities currently allowed to author blocks
+    pub fn authorities() -> Vec<T::AuthorityId> {
+        Self::effective_authorities().into_inner()
+    }
+
+    /// Authorities of the override if there is one, the noted authorities otherwise
+    fn effective_authorities() -> AuthoritiesOf<T> {
+        AuthoritiesOverride::<T>::get()
+            .map(|pinned| pinned.authorities)
+            .unwrap_or_else(Authorities::<T>::get)
+    }
+
+    /// Remove the authorities override if it lasted until block `now`
+    fn expire_authorities_override(now: BlockNumberFor<T>) -> Weight {
+        let expired = AuthoritiesOverride::<T>::get()
+            .map(|pinned| now >= pinned.expires_at)
+            .unwrap_or(false);
+        if !expired {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let weight = Self::end_authorities_override();
+        Self::deposit_event(Event::AuthoritiesOverrideExpired);
+
+        T::DbWeight::get().reads_writes(2, 1).saturating_add(weight)
+    }
+
+    /// Remove the authorities override if it lasted until the noted `session_index`. Returns
+    /// the weight of `OnAuthoritiesChanged` if it was called.
+    fn expire_authorities_override_at_session(session_index: u32) -> Weight {
+        let expired = AuthoritiesOverride::<T>::get()
+            .and_then(|pinned| pinned.until_session)
+            .map(|until_session| session_index >= until_session)
+            .unwrap_or(false);
+        if !expired {
+            return Weight::zero();
+        }
+
+        let weight = Self::end_authorities_override();
+        Self::deposit_event(Event::AuthoritiesOverrideExpired);
+
+        weight
+    }
+
+    /// Remove the authorities override, notifying `OnAuthoritiesChanged` if the noted
+    /// authorities differ from the pinned ones. Returns the weight of the hook.
+    fn end_authorities_override() -> Weight {
+        let Some(pinned) = AuthoritiesOverride::<T>::take() else {
+            return Weight::zero();
+        };
+
+        let authorities = Authorities::<T>::get();
+        if pinned.authorities == authorities {
+            return Weight::zero();
+        }
+
+        T::OnAuthoritiesChanged::on_authorities_changed(
+            &pinned.authorities,
+            &authorities,
+            Self::last_noted_session(),
+        )
+    }
+
+    /// Authorities assigned to this container chain in the next orchestrator session. Empty if
+    /// the next assignment is not known yet.
+    pub fn next_authorities() -> Vec<T::AuthorityId> {
+        NextAuthorities::<T>::get().into_inner()
+    }
+
+    /// Noted authorities as aura authorities, to implement `AuraApi::authorities` for tooling
+    /// that reads the authorities from the aura runtime API. Authorities that are not valid
+    /// aura keys are skipped.
+    pub fn aura_authorities<AuraId: ByteArray>() -> Vec<AuraId>
+    where
+        T::AuthorityId: ByteArray,
+    {
+        to_aura_authorities(&Self::effective_authorities())
+    }
+
+    /// Collators of the orchestrator chain in the last noted orchestrator session
+    pub fn orchestrator_authorities() -> Vec<T::AuthorityId> {
+        OrchestratorAuthorities::<T>::get().into_inner()
+    }
+
+    /// Authority expected to author a block in the given slot, if any
+    pub fn expected_author(slot: u32) -> Option<T::AuthorityId> {
+        T::AuthorSelectionStrategy::select_author(
+            &Self::effective_authorities(),
+            slot,
+            Self::last_noted_session().unwrap_or_default(),
+        )
+    }
+
+    /// Blocks authored and slots missed by each author in the given orchestrator session
+    pub fn block_production_stats(
+        session_index: u32,
+    ) -> Vec<(T::AuthorityId, BlockProductionStats)> {
+        AuthorStats::<T>::iter_prefix(session_index).collect()
+    }
+
+    /// Attribute the slots between the last block and the current one to the authorities that
+    /// were expected to author them. Only the last `MaxAuthorities` missed slots are attributed,
+    /// so that a stall of the whole chain is not charged repeatedly to every authority.
+    fn note_missed_slots() -> Weight {
+        let slot = T::SlotBeacon::slot();
+        let last_slot = LastBlockSlot::<T>::get();
+        LastBlockSlot::<T>::put(slot);
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        let (Some(last_slot), Some(session_index)) = (last_slot, Self::last_noted_session()) else {
+            return weight.saturating_add(T::DbWeight::get().reads(1));
+        };
+        let authorities = Self::effective_authorities();
+        weight = weight.saturating_add(T::DbWeight::get().reads(3));
+
+        // The ordering is computed once for all the missed slots, which are at most
+        // `MaxAuthorities`
+        let ordering = T::AuthorSelectionStrategy::slot_ordering(&authorities, session_index);
+        if ordering.is_empty() {
+            return weight;
+        }
+        let first_missed = last_slot
+            .saturating_add(1)
+            .max(slot.saturating_sub(T::MaxAuthorities::get()));
+        let mut missed = alloc::vec![0u32; ordering.len()];
+        for missed_slot in first_missed..slot {
+            missed[(missed_slot as usize) % ordering.len()] += 1;
+        }
+
+        for (author, count) in ordering.into_iter().zip(missed) {
+            if count == 0 {
+                continue;
+            }
+            AuthorStats::<T>::mutate(session_index, author, |stats| {
+                stats.missed = stats.missed.saturating_add(count);
+            });
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        weight
+    }
+
+    /// Count the current block for its author, in the session of the noted authorities
+    fn note_authored_block() {
+        let (Some(author), Some(session_index)) =
+            (Self::block_author(), Self::last_noted_session())
+        else {
+            return;
+        };
+
+        AuthorStats::<T>::mutate(session_index, author, |stats| {
+            stats.authored = stats.authored.saturating_add(1);
+        });
+    }
+
+    fn block_author() -> Option<T::AuthorityId> {
+        let digest = frame_system::Pallet::<T>::digest();
+        let pre_runtime_digests = digest.logs.iter().filter_map(|d| d.as_pre_runtime());
+
+        T::FindAuthor::find_author(pre_runtime_digests)
+    }
+
+    /// Orchestrator session of the last successfully noted authorities
+    pub fn last_noted_session() -> Option<u32> {
+        LastNotedOrigin::<T>::get().map(|origin| origin.orchestrator_session_index)
+    }
+
+    /// Authorities noted for the given orchestrator session, if it is still part of the history
+    pub fn authorities_for_session(session_index: u32) -> Option<Vec<T::AuthorityId>> {
+        AuthoritiesHistory::<T>::get(session_index).map(BoundedVec::into_inner)
+    }
+
+    /// Authorities that were allowed to author the container block `block_number`, according to
+    /// the orchestrator session that was noted at that height.
+    ///
+    /// Returns `None` if the block is older than the first session kept in the history.
+    /// Authorities set through `set_authorities` are not part of the history.
+    pub fn authorities_at(block_number: BlockNumberFor<T>) -> Option<Vec<T::AuthorityId>> {
+        let sessions = AuthoritiesHistorySessions::<T>::get();
+        let (session_index, _) = sessions
+            .iter()
+            .rev()
+            .find(|(_, first_block)| *first_block <= block_number)?;
+
+        Self::authorities_for_session(*session_index)
+    }
+}
+
+/// Authorities noted by the pallet, bounded by `MaxAuthorities`
+pub type AuthoritiesOf<T> = BoundedVec<<T as Config>::AuthorityId, <T as Config>::MaxAuthorities>;
+
+/// Authorities override stored by the pallet
+pub type PinnedAuthoritiesOf<T> = PinnedAuthorities<AuthoritiesOf<T>, BlockNumberFor<T>>;
+
+/// Registration of this chain in the orchestrator registrar
+pub type ParaInfoOf<T> = ParaInfo<<T as Config>::ManagerAccountId, <T as Config>::RegistrarBalance>;
+
+#[cfg(any(test, feature = "try-runtime"))]
+impl<T: Config> Pallet<T> {
+    /// Check the invariants of the pallet storage
+    pub fn do_try_state(n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+        // The inherent is mandatory, so every block but genesis must have noted the authorities,
+        // and the flag is only ever set to true
+        if !n.is_zero() {
+            ensure!(
+                DidSetOrchestratorAuthorityData::<T>::exists(),
+                "DidSetOrchestratorAuthorityData must be set after every block"
+            );
+        }
+        ensure!(
+            !DidSetOrchestratorAuthorityData::<T>::exists()
+                || DidSetOrchestratorAuthorityData::<T>::get(),
+            "DidSetOrchestratorAuthorityData must not be stored as false"
+        );
+
+        let authorities = Authorities::<T>::get();
+        for (i, authority) in authorities.iter().enumerate() {
+            ensure!(
+                !authorities[i + 1..].contains(authority),
+                "Authorities must not contain duplicates"
+            );
+        }
+
+        ensure!(
+            OrchestratorParaId::<T>::get() != T::SelfParaId::get(),
+            "OrchestratorParaId must not be the para id of this chain"
+        );
+
+        if let Some(pinned) = AuthoritiesOverride::<T>::get() {
+            ensure!(
+                pinned.expires_at > n,
+                "AuthoritiesOverride must be removed once it expires"
+            );
+        }
+
+        Ok(())
     }
 }
 
+/// Convert `authorities` to aura authorities, keeping their order and skipping those that are not
+/// valid aura keys
+fn to_aura_authorities<AuthorityId: ByteArray, AuraId: ByteArray>(
+    authorities: &[AuthorityId],
+) -> Vec<AuraId> {
+    authorities
+        .iter()
+        .filter_map(|authority| AuraId::from_slice(authority.as_slice()).ok())
+        .collect()
+}
+
+/// Header of the orchestrator chain, as stored in the relay para heads
+type OrchestratorHeader = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+
+/// How the orchestrator chain runs, which determines where the authorities are read from
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub enum OrchestratorChainMode {
+    /// The orchestrator is a parachain (dancebox): the authorities are read from the
+    /// orchestrator chain state proof
+    Parachain,
+    /// The orchestrator is a solochain (starlight): the authorities are read from the relay
+    /// chain state proof, and the orchestrator chain state proof must be empty
+    Solochain,
+}
+
+/// Why the orchestrator stopped this chain
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum HaltReason {
+    /// This chain is paused in the orchestrator registrar
+    Paused,
+    /// This chain is not among the registered container chains of the orchestrator, either
+    /// because it was deregistered or because its registration is not complete
+    NotRegistered,
+}
+
+/// What to do when the authorities-noting inherent carries a malformed proof
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ProofFailurePolicy {
+    /// Panic, making the block invalid
+    Panic,
+    /// Reject the proof and keep the previously noted authorities
+    KeepPrevious,
+    /// Reject the proof and clear the noted authorities
+    Clear,
+}
+
+/// Provenance of a set of noted authorities
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct NotedOrigin {
+    /// Orchestrator session the authorities were assigned for
+    pub orchestrator_session_index: u32,
+    /// Number of the orchestrator block the authorities were read from. `None` if they were read
+    /// from the relay chain state (solochain orchestrator).
+    pub orchestrator_block_number: Option<BlockNumber>,
+    /// Hash of the orchestrator block the authorities were read from. `None` if they were read
+    /// from the relay chain state (solochain orchestrator).
+    pub orchestrator_block_hash: Option<H256>,
+    /// Number of the relay parent whose state proof was verified
+    pub relay_parent_number: BlockNumber,
+    /// Storage root of the relay parent whose state proof was verified
+    pub relay_parent_storage_root: H256,
+}
+
+/// Authorities pinned by governance instead of the noted ones, see `set_authorities_override`
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PinnedAuthorities<Authorities, BlockNumber> {
+    /// Authorities allowed to author blocks while the override lasts
+    pub authorities: Authorities,
+    /// Block from which the noted authorities are used again
+    pub expires_at: BlockNumber,
+    /// Orchestrator session from which the noted authorities are used again, if it is noted
+    /// before `expires_at`
+    pub until_session: Option<u32>,
+}
+
+/// Authorities read from the orchestrator state proof
+struct NotedAuthorities<T: Config> {
+    /// Orchestrator session the authorities were read for
+    session_index: u32,
+    /// Authorities assigned to this chain in the current session
+    authorities: Vec<T::AuthorityId>,
+    /// Authorities assigned to this chain in the next session, if already known
+    next_authorities: Vec<T::AuthorityId>,
+    /// Collators assigned to the orchestrator chain in the current session
+    orchestrator_authorities: Vec<T::AuthorityId>,
+    /// Orchestrator session keys of the authorities, for those that were part of the proof
+    session_keys: Vec<(T::AuthorityId, T::OrchestratorSessionKeys)>,
+    /// Registration of this chain, if it was part of the proof. `Some(None)` if the proof shows
+    /// that this chain is not registered.
+    para_info: Option<Option<ParaInfoOf<T>>>,
+    /// Orchestrator header the authorities were read from, `None` in solochain mode
+    orchestrator_header: Option<OrchestratorHeader>,
+}
+
 #[derive(Encode)]
 #[cfg_attr(feature = "std", derive(Debug, Decode))]
 pub enum InherentError {
@@ -402,19 +1830,30 @@ impl InherentError {
     }
 }
 
+/// Call filter that lets every call through, except while the chain is halted, when only the
+/// inherents and the calls in `AllowedWhileHalted` are allowed. Meant to be used as
+/// `BaseCallFilter`; inherents are recognized as the calls applied before
+/// `frame_system::Pallet::note_inherents_applied`, which the executive calls once all the
+/// inherents of the block are applied.
+pub struct NotHalted<T, AllowedWhileHalted>(PhantomData<(T, AllowedWhileHalted)>);
+
+impl<T, AllowedWhileHalted, C> Contains<C> for NotHalted<T, AllowedWhileHalted>
+where
+    T: Config,
+    AllowedWhileHalted: Contains<C>,
+{
+    fn contains(call: &C) -> bool {
+        !Pallet::<T>::is_halted()
+            || !frame_system::Pallet::<T>::inherents_applied()
+            || AllowedWhileHalted::contains(call)
+    }
+}
+
 pub struct CanAuthor<T>(PhantomData<T>);
 
 impl<T: Config> nimbus_primitives::CanAuthor<T::AuthorityId> for CanAuthor<T> {
     fn can_author(author: &T::AuthorityId, slot: &u32) -> bool {
-        let authorities = Pallet::<T>::authorities();
-
-        if authorities.is_empty() {
-            return false;
-        }
-
-        let expected_author = &authorities[(*slot as usize) % authorities.len()];
-
-        expected_author == author
+        Pallet::<T>::expected_author(*slot).as_ref() == Some(author)
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -424,7 +1863,7 @@ impl<T: Config> nimbus_primitives::CanAuthor<T::AuthorityId> for CanAuthor<T> {
         // this does not affect anything related to benchmarked values
         if authorities.is_empty() {
             authorities = T::BenchmarkHelper::authorities_on_empty();
-            Authorities::<T>::put(authorities.clone());
+            Authorities::<T>::put(AuthoritiesOf::<T>::truncate_from(authorities.clone()));
         }
         authorities
     }
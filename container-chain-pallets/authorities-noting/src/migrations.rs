@@ -0,0 +1,92 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Storage migrations for pallet-cc-authorities-noting
+
+use {
+    crate::{Authorities, AuthoritiesOf, Config, Pallet},
+    alloc::vec::Vec,
+    core::marker::PhantomData,
+    frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    },
+};
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Re-encodes the authorities stored as an unbounded vector as a `BoundedVec`, truncating them
+/// if there are more than `MaxAuthorities`. Only runs if the on-chain storage version is 0.
+pub type MigrateToBoundedAuthorities<T> = VersionedMigration<
+    0,
+    1,
+    UncheckedMigrateToBoundedAuthorities<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Unversioned version of [`MigrateToBoundedAuthorities`]. The encoding is the same, but the
+/// values exceeding the bound would fail to decode.
+pub struct UncheckedMigrateToBoundedAuthorities<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedMigrateToBoundedAuthorities<T> {
+    fn truncate(authorities: Vec<T::AuthorityId>) -> AuthoritiesOf<T> {
+        if authorities.len() > T::MaxAuthorities::get() as usize {
+            log::warn!(
+                "Truncating {} noted authorities to {}",
+                authorities.len(),
+                T::MaxAuthorities::get()
+            );
+        }
+        AuthoritiesOf::<T>::truncate_from(authorities)
+    }
+}
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToBoundedAuthorities<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Authorities::<T>::translate(|authorities: Option<Vec<T::AuthorityId>>| {
+            authorities.map(Self::truncate)
+        })
+        .is_err()
+        {
+            log::error!("Failed to decode Authorities");
+        }
+
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let authorities: Vec<T::AuthorityId> =
+            frame_support::storage::unhashed::get(&Authorities::<T>::hashed_key())
+                .unwrap_or_default();
+
+        Ok(authorities.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let authorities = Vec::<T::AuthorityId>::decode(&mut state.as_slice())
+            .map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+        ensure!(
+            Authorities::<T>::get() == Self::truncate(authorities),
+            "Authorities not migrated"
+        );
+
+        Ok(())
+    }
+}
@@ -15,15 +15,17 @@
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
 
 use {
-    crate::{self as authorities_noting_pallet, BlockNumberFor, Config},
+    crate::{
+        self as authorities_noting_pallet, BlockNumberFor, Config, NotHalted, OnAuthoritiesChanged,
+        ProofFailurePolicy, RoundRobin,
+    },
     cumulus_pallet_parachain_system::{RelayChainState, RelaychainStateProvider},
     cumulus_primitives_core::ParaId,
     frame_support::{
         inherent::{InherentData, ProvideInherent},
         parameter_types,
-        traits::{
-            ConstU32, ConstU64, Everything, OnFinalize, OnInitialize, UnfilteredDispatchable,
-        },
+        traits::{ConstU32, ConstU64, Nothing, OnFinalize, OnInitialize, UnfilteredDispatchable},
+        weights::Weight,
     },
     frame_system::RawOrigin,
     parity_scale_codec::Encode,
@@ -46,12 +48,13 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage},
         AuthoritiesNoting: authorities_noting_pallet::{Pallet, Call, Storage, Event<T>},
     }
 );
 
 impl frame_system::Config for Test {
-    type BaseCallFilter = Everything;
+    type BaseCallFilter = NotHalted<Test, Nothing>;
     type BlockWeights = ();
     type BlockLength = ();
     type DbWeight = ();
@@ -83,9 +86,23 @@ impl frame_system::Config for Test {
     type ExtensionsWeightInfo = ();
 }
 
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
 parameter_types! {
     pub const ParachainId: ParaId = ParaId::new(200);
     pub const OrchestratorParachainId: ParaId = ParaId::new(1000);
+    pub static MalformedProofPolicy: ProofFailurePolicy = ProofFailurePolicy::Panic;
+    pub static AuthoritiesGracePeriod: u64 = 0;
+    pub static AuthoritiesChangedCalls: Vec<(Vec<AccountId>, Vec<AccountId>, Option<u32>)> = vec![];
+    pub const AuthoritiesChangedWeight: Weight = Weight::from_parts(1_000, 10);
+    pub static MockNow: u64 = 0;
+    pub static MaxRelayTimeDrift: Option<u64> = None;
+    pub static NoteAuthoritySessionKeys: bool = true;
 }
 
 pub(crate) const MOCK_RELAY_ROOT_KEY: &[u8] = b"MOCK_RELAY_ROOT_KEY";
@@ -109,11 +126,83 @@ impl RelaychainStateProvider for MockRelayStateProvider {
     }
 }
 
+pub struct RecordAuthoritiesChanged;
+
+impl OnAuthoritiesChanged<AccountId> for RecordAuthoritiesChanged {
+    fn on_authorities_changed(
+        old_authorities: &[AccountId],
+        new_authorities: &[AccountId],
+        session_index: Option<u32>,
+    ) -> Weight {
+        AuthoritiesChangedCalls::mutate(|calls| {
+            calls.push((
+                old_authorities.to_vec(),
+                new_authorities.to_vec(),
+                session_index,
+            ))
+        });
+
+        AuthoritiesChangedWeight::get()
+    }
+
+    fn max_weight() -> Weight {
+        AuthoritiesChangedWeight::get()
+    }
+}
+
+/// Uses the block number as slot, so that skipping block numbers in `BlockTests` misses slots
+pub struct BlockNumberSlotBeacon;
+
+impl nimbus_primitives::SlotBeacon for BlockNumberSlotBeacon {
+    fn slot() -> u32 {
+        System::block_number() as u32
+    }
+}
+
+/// Time set with `MockNow`, in milliseconds
+pub struct MockUnixTime;
+
+impl frame_support::traits::UnixTime for MockUnixTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::from_millis(MockNow::get())
+    }
+}
+
+/// Every block is authored by the expected author
+pub struct ExpectedAuthor;
+
+impl frame_support::traits::FindAuthor<AccountId> for ExpectedAuthor {
+    fn find_author<'a, I>(_digests: I) -> Option<AccountId>
+    where
+        I: 'a + IntoIterator<Item = (frame_support::ConsensusEngineId, &'a [u8])>,
+    {
+        AuthoritiesNoting::expected_author(System::block_number() as u32)
+    }
+}
+
 // Implement the sudo module's `Config` on the Test runtime.
 impl Config for Test {
     type SelfParaId = ParachainId;
     type RelayChainStateProvider = MockRelayStateProvider;
     type AuthorityId = AccountId;
+    type MaxAuthorities = ConstU32<5>;
+    type MaxOrchestratorAuthorities = ConstU32<3>;
+    type AuthoritiesHistoryDepth = ConstU32<4>;
+    type MalformedProofPolicy = MalformedProofPolicy;
+    type AuthoritiesGracePeriod = AuthoritiesGracePeriod;
+    // Registered twice to exercise the tuple implementation
+    type OnAuthoritiesChanged = (RecordAuthoritiesChanged, RecordAuthoritiesChanged);
+    type FindAuthor = ExpectedAuthor;
+    type SlotBeacon = BlockNumberSlotBeacon;
+    type AuthorSelectionStrategy = RoundRobin;
+    type ManagerAccountId = AccountId;
+    type RegistrarBalance = u128;
+    type OrchestratorValidatorId = [u8; 32];
+    type OrchestratorSessionKeys = u128;
+    type NoteAuthoritySessionKeys = NoteAuthoritySessionKeys;
+    type UnixTime = MockUnixTime;
+    type RelaySlotDuration = ConstU64<6000>;
+    type MaxRelayTimeDrift = MaxRelayTimeDrift;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
@@ -14,14 +14,1209 @@
 // You should have received a copy of the GNU General Public License
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
 
+/// Orchestrator session of the assignments in `ENCODED_PROOFS`
+pub const SESSION_INDEX: u32 = 1000;
+/// Para id of the container chain assigned the authorities in `ENCODED_PROOFS`
+pub const CONTAINER_CHAIN_PARA_ID: u32 = 200;
+/// Para id of the orchestrator chain whose head is proved in `ENCODED_PROOFS`
+pub const ORCHESTRATOR_PARA_ID: u32 = 1000;
+
 pub const ENCODED_PROOFS: &[(u32, (&str, &[&str]), (&str, &[&str]))] = &[
-    (0, ("9ff9b98ced94a223a26c3947b31e46a08dc2c30cd7216806ec9196ca268cdcee", &[
-        "3f39cd710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e803000060b6614387c5fbd1e96f01c86798880f4a40c3b09f47cc10c3211d075384c453",
-        "89010000000000000000000000000000000000000000000000000000000000000000008fe3df1399a8cba829e6ddf26429a08b7af18b1184ecd6802b86f6f805afeb4f000000000000000000000000000000000000000000000000000000000000000000",
-    ]), (
-         "8fe3df1399a8cba829e6ddf26429a08b7af18b1184ecd6802b86f6f805afeb4f", &[
-            "7f000ec5070d609dd3497f72bde07fc96ba072763800a36a99fdfc7c10f6415f6ee61000000000",
-            "7f180be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0cb4def25cfda6ef3a000000005c0004c8000000080a000000000000000b00000000000000",
-            "800050804dd3619b3a409b5ba39167d76862b61107a8b8b41f3c5a6227335326311023df8015253e2823898d5a0418b0cdd3c6b6ac065c57db18e41d05a7149cca8f8262fb",
+    (0, ("56f145fbcf6e5147fc621faf930cabf71d601357dd3cb3e3fa36444d1c8b5ef2", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000cc8aa082955aae3fe8f9e671d08237ffcafcb479159e6351a24d5ba9332bedad",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780c1c733aa9edb1404b2cd0bf42db602382adc7759a011986226dc482ec441e9d9",
+        "8901000000000000000000000000000000000000000000000000000000000000000000d6dc0326477b5f0c5f60dc8c163986e9f0af950f03ad2ffe086e77a119ab32d1000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "d6dc0326477b5f0c5f60dc8c163986e9f0af950f03ad2ffe086e77a119ab32d1", &[
+            "7f000ec5070d609dd3497f72bde07fc96ba072763800a36a99fdfc7c10f6415f6ee610e8030000",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80ca6f0eb41ed5485806c944f481dd0b927436191639aa76adcf06cfcb360bb44580778c7dbd02267fb7e2f278858cf894f7e3c301b04b739695c92335ab051896ba",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c54570dc7217647a32b0be90300001c0004c800000000545706ff6f7d467b87a9e80300001c0004c800000000",
+        ])),
+    (1, ("3004d7f95121a831f7f0a109a02f310db41a413e09c1ed803f4545b005be574d", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300007b08157568aa997d995b2081808b75a82fe918565b65b450a3c7468542e45fc4",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780a391c1c04b89c4ad00286dade28735cbe835aaa5c7589f198d85a260074c2c1b",
+        "8901000000000000000000000000000000000000000000000000000000000000000000f9496198d298f2159ac0d326cc350729b878ace0c533dbac8c8d7b9bf97c96e1000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "f9496198d298f2159ac0d326cc350729b878ace0c533dbac8c8d7b9bf97c96e1", &[
+            "0004c8000000040100000000000000020000000000000003000000000000000400000000000000",
+            "0004c8000000040500000000000000060000000000000007000000000000000800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "3706ff6f7d467b87a9e8030000797cd07b3ddfe726ecc21eab3f6ee145934efdda770077e2ce22a89e7a3d2887",
+            "370dc7217647a32b0be90300007460228073cc2aebbd867ba59f320fac8a9cc5221c146a734f53e62ec9dec522",
+            "3f500c014e6bf8b8c2c011e7290b85696bb37713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "7f380380404683fc89e8233450c8aa1950c98611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80b6b04305661807f61de46e5b8fa19fe6717a03c596d34ccef7ebfc248ffd54f48080c6f432e352daa6aa9f54c5ccabade604a57e79bd1bf8678a66138a419f43e3",
+            "8102c0008062772dce829b0a2ac1e19fe06f37f0cee4643803d35b95e57baf20a29e12b9d9545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9f0ec5070d609dd3497f72bde07fc96ba090008094d65b2c69a9aaeaba2c1d25def8bb3168d3270a0b951493e0c2a534560701e1802ac924bb95142492b36beb8b09add9ef4c789ff5dba21f26accd49007ba5fdb0",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c801ca49797638a92c214b6cefb04636c54d8d7b7a01448933689adbe073bac218480323508ff5936495a094fe441e9ed165be109c9b7118fe20a1ef93645c2b2158e",
+        ])),
+    (2, ("242a39f9204fc2e6c568f7eac9cbc7c060668886e697e13655b021c189598956", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e803000028ddd3e228efc8532a14a71ec77a628ee0445a78fb9145000be744f9d3373e8f",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780e7101e23949fe773050d770cbee6786a7077df7fa521f83bf782afdf8481f3c6",
+        "8901000000000000000000000000000000000000000000000000000000000000000000e98598d07b9e470e1660b8e76204cfed9c1855908ccc4c0ffb3a7da76b430594000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "e98598d07b9e470e1660b8e76204cfed9c1855908ccc4c0ffb3a7da76b430594", &[
+            "0004c80000000801000000000000000200000000000000030000000000000004000000000000000500000000000000060000000000000007000000000000000800000000000000",
+            "0004c80000000809000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f000000000000001000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "3706ff6f7d467b87a9e803000063f232ce586ae11d7aed8212218914cab0abe37c0e5032be0e86870b321db0ac",
+            "370dc7217647a32b0be90300003b92652e5f8eb37737712c68012520225e830500aae0b0691b47bd42cdebed4c",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e801477072940d1e2c545f9b1a94eb0499acbafe04b54618e777e7b6379c367ea79808ab05c06fbb7af7ebdd611e49034cfb71f3262dd9172eb774343ad6b987de276",
+            "8102c000805ccfee7d42c96c2d82c8267ed2f1ad8a599bf6263d5195e5b5293aa3fe8654ca545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950201080ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3a0008088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc14",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000809911a03957caf49a96299ef87f21e99d67fa11187f5bfaa885469993324cab978052b77e2448d06b4993e80c78c2aa25626325aa0abef7014ad5938c82ca6e772d",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c800b59a4cb4928e02947b3646161b498047b4886838df2d3e8382e05d48655fd2d801b0cbc85a4ad18bfb0e3792b34fb61fa6578c000643b39a5ad585d0de66683ee",
+        ])),
+    (3, ("2173e0371eb3b161506ff75c3a44fef17a4bc9afc8f5878b5a3373ccff4895d1", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300008bcbf436fa6c3c82bd21fd4d047e0f981923a4e76d733c591029041398a936f9",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e178092d39b72e9d1c519e837ddaec47c701b608e0923f4e57993e21973f768d9885e",
+        "8901000000000000000000000000000000000000000000000000000000000000000000a8aa4c06cd478d1e875a58102381bbcfe5e128d786dca917fccf076b521e21b8000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "a8aa4c06cd478d1e875a58102381bbcfe5e128d786dca917fccf076b521e21b8", &[
+            "0004c80000000c0100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c00000000000000",
+            "0004c80000000c0d000000000000000e000000000000000f00000000000000100000000000000011000000000000001200000000000000130000000000000014000000000000001500000000000000160000000000000017000000000000001800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "3706ff6f7d467b87a9e8030000792f2170157c8c37639af18dd48a1e5911804b62098dbad3f50e95baf8abb521",
+            "370dc7217647a32b0be9030000981d1adc7245dd0b9bbefe25bf1cd936a52d8a9038662dec5b736908b236de5a",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e8079e27541073f56c69ec374f5a01b3f68fe7aa5d029eb8cfb62aed2f033ef23fe80e2d665e44ddcdbb3d9bf556e3cbc652d5dd9d8cc3605d102dd5ba4020c9032bb",
+            "8102c0008043c9e25261f6f48424e3b0d9f4db11c7fa48bb2a0dc99e96403810c5de15fe2b545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950211080da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb4574380ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3a0108088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc14801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000804cda49de2dcfab9d6e2f33d49e641708a4b3e1b3c2eb8e0a6fccdc51bb34847280cbdeeb55a3f7b9f61624ccb970d93ce6c40bde931f46bab95392004f952bc953",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c8001f527a202703d10cc9faad49d447eee2cc49cc30e8f0c3b7aea4b359e814fea80353276fd4f64b523c4da2c370395f1e4a3ba07965ff2df41c616c2fa0c675b4a",
+        ])),
+    (4, ("c984f5b472508d264fdac01c95bb2f02135d32774ee1dfd09ea4649263e6c195", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000d89a46502873cac2cb4877d8f0ad01de239da954e8aed6e6d8782e0ded7cc694",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780995742bf3a197ad659287941423b82a494a066f23cf3a24e7f637d8642428891",
+        "8901000000000000000000000000000000000000000000000000000000000000000000670ddb84db79730fbed7b6bd766aece688567d72e29a526abde533a36bbdb8cf000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "670ddb84db79730fbed7b6bd766aece688567d72e29a526abde533a36bbdb8cf", &[
+            "0004c8000000100100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f000000000000001000000000000000",
+            "0004c8000000101100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f000000000000002000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "3706ff6f7d467b87a9e803000031716fdc8af603f54834bd6ca7ef25b3ea725dce21e2dd17c12b67ab6a7fec48",
+            "370dc7217647a32b0be9030000358a34c5efb2707abd67ed1feb31ca9e48a205e1a031fb12f972a96012a0ff3c",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80b89214bc719c35b540b9b3cca59ef6d9070a964c662ca6010e6aaecc4ad4cde98092b8f99bd3b456491474698c962601b5eacfbcca8f9a6893640f6cd984d1731d",
+            "8102c000805eed9e0dcaa5c0e340d2575000a475d0fffe9934cbcd71581919b9f4d8a994df545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950211280da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb4574380ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca3880c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3a1108047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d888088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc14801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000806a738aa9ec2d44a60b54ba04f6116afe4a6986a377fd68a750c67e612486791780bbd4851842e49690cf49ecf668c3343eec5a7089db256378c9b3443d5187888c",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80e5529767f274503341fecabde74f407314bb920be5481c7cd18e18aa0cbe026480bedcf17bb27d0e1d6280b34994b4602f6c81ab0cf050a6cf5354c48545da3a81",
+        ])),
+    (5, ("0f1c5294f8f92b965f8cfd77447234cd8ff487c886578a572fab89305899259c", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e803000009a36714d183351dd64cd79e83ab3dc85032c03aafab50d53fca79ddd9d6d01c",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e17802e3d2c2a0998f28cd86bf8fe5eb7ad011dcdbb4ba1e1ab9c52a52e54bbf22d12",
+        "89010000000000000000000000000000000000000000000000000000000000000000000fb3b407fef4f229df4cdb3e92ae82acc300e03ff793fa08c43245193fac2c96000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "0fb3b407fef4f229df4cdb3e92ae82acc300e03ff793fa08c43245193fac2c96", &[
+            "0004c8000000140100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000",
+            "0004c800000014150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f00000000000000200000000000000021000000000000002200000000000000230000000000000024000000000000002500000000000000260000000000000027000000000000002800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "3706ff6f7d467b87a9e8030000bb1163df9ba7e50ce5616b716c446e96e7212cc0c00b327089a3ef3548729e5e",
+            "370dc7217647a32b0be90300002719eef6f79e695996e5fcf79958daac9d0af00c809a24ca5d36ec8fc0ce5691",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0a73442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e806773381f82ebb70d75a5f5dedb5a01c362348a36e399671333243836aa2c2ef980c996465c5c41307bc7acdbf09336260ddd6ea4f6edbc1c5634f020366ff13304",
+            "8102c00080e5e65156fbea3cbdd7c95e28770d6caa97791f28b6d4376b2e6dad76baf94265545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950231280da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb457438035a7dbc0e7be0fa26dd91383c64a921db414b613dd7ad1be1ba5f890d4de565180ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca3880c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b1108047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc14801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080682005fdb032287403fd8e388441e6e599210c3eda08115085ac8d59bb40a8b180086ad17646f4b0c2a0c127f54dc803a5a89a6c23806ccf81ad3b69c962655042",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80d936602f9be5b945eeb42602f9323ebd790760b05629a7658da16b104eb2028680cfe8ec5479dcfbceff5297e087e9c872dee2656fc8b8b778f2199388225ab215",
+        ])),
+    (6, ("3fe8a95176ca3d7b837c640e79f1858f6669483d9f9b4a1450e4c41dd4ab6ca1", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300008268867efad34a7b5f9523939179b6389c0e3d90913558662748d1481a50af7e",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780836e21f38ef9dbd89682334e6ba239eeb771ee00e455b989c04c50be89154700",
+        "89010000000000000000000000000000000000000000000000000000000000000000003122c1bdf287341a6065befdcbd1c663c2ab230c6378a8ae0873ad7fbd047fbd000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "3122c1bdf287341a6065befdcbd1c663c2ab230c6378a8ae0873ad7fbd047fbd", &[
+            "0004c8000000180100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f00000000000000100000000000000011000000000000001200000000000000130000000000000014000000000000001500000000000000160000000000000017000000000000001800000000000000",
+            "0004c80000001819000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f000000000000003000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "3706ff6f7d467b87a9e8030000964322f3dd4b6c4dc489f73b01a60c3e74a419d946c15c129a76857ec6b4e359",
+            "370dc7217647a32b0be9030000c19e75a10b8b2645a15c8802421cdc54a306416280fe84b8fc5b52245e6a36bc",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300adc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0a73442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f1a0fff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80f25b2b1347413edd67a1f87eee8f6ee3ab4f195752f88890c01539339299effc80645a24dc6d29acf963e5fd9453961a05c9931c318069a3444ada16f9978f8dbe",
+            "8102c000801da6a4f6d957c898b94d25df6e96cb75bedfe177d9aa1becd872773e49aee08a545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950235280da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb457438035a7dbc0e7be0fa26dd91383c64a921db414b613dd7ad1be1ba5f890d4de565180ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca3880c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de806bd76c0f970330ebe2de97d3094cf7e753a0091cb48db982f2650e43ddbf0289",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b3108047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880dc1aa12104c2133a3776d5e20b8d744bd5cf4912bac600e63af36821b5ca03e280ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc14801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000809a55f1c4690ffae1f3846f47cb768d8a30f35e0709b4b828c0505fec096c30a880769cbc66888f049c2581ffcb223aa096822e968e1be9d41c000169b156b75b80",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80667e0cfa84319679490c758e9cb4d4fee2cdfdec4c0539b638a617c1ae61649980248c46134f0df657a4ee1ffe3e605fd999d4e3122fb334023d78e0952103fbf7",
+        ])),
+    (7, ("de9c5a1026b77a85e95da979a9a8f0934578fa7b8640b4250ad5cca6f1474b60", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000aba435b0d25266caf2c52d0c3d3ed51a263d2a3a5920d21b0e9d8fb75703ef7f",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e17803b56e66b35acffbcf331a5ecc7fcd54b17a57acefb39695e03775a6c78d38733",
+        "890100000000000000000000000000000000000000000000000000000000000000000015202619d7910d1a7d8c7aeca24585f593fd02e9c43051ee8444f570bc680fbb000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "15202619d7910d1a7d8c7aeca24585f593fd02e9c43051ee8444f570bc680fbb", &[
+            "0004c80000001c0100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c00000000000000",
+            "0004c80000001c1d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f00000000000000300000000000000031000000000000003200000000000000330000000000000034000000000000003500000000000000360000000000000037000000000000003800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "3706ff6f7d467b87a9e80300004e3b621f2c81563b82baa4ce2eb99bd6507576b2c8a215b036b1f97305223058",
+            "370dc7217647a32b0be9030000c8aa0ac75bfde9f4f513d9a866e01e97ccb3a6a07a462d4d7592d41da5bd421f",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0fff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "800006802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e805b3c6f7e909528e1a5fea7bb6e9e49ff55a54da2c3ffe12d452fce6db1a7359380784d4a2c741f3c41fd61a210e504b09928e42095f17dc8b0391105f6a6d5f9fa",
+            "8102c0008066b26bb2c05b8b66c4cb2850aaf273b4bed167a5a34bef0d5d55e342e7c605a2545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950235280da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb4574380a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca3880c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de806bd76c0f970330ebe2de97d3094cf7e753a0091cb48db982f2650e43ddbf0289",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b3108047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d88802acfb3b599d1b868215a06fcae980b46d8dbe10f04540157ce37cb44853ca3a080ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc14801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a",
+            "9f0ec5070d609dd3497f72bde07fc96ba090008095057d3efc66ce41367f45f1d6c148fe6b3461f8b9dc10e5d3397b654f1cf556802053f855cee5ac88a95621893a062defee4f4edf804fd56d54bd5ccb974bbcf6",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c8094c93b61573369a0772ef7280db4c260335490fb093256e207b5e008a8f6cdd980a7fd334b938c6d2f08ae878ace86da6c939e5e9a2310d8b644e2ea5749489c1b",
+        ])),
+    (8, ("f839951b467463c71950d9cdcb6f54657569aa4ee49e302bd41ca84c09fa25f6", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000c9a2de9e8408d4bdca39de32d931398574304365ef15360bacdc829527a4b6f9",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e17804ff8f9e04048064336bdb3866117c482557f978bacf69621b1b9c2f016d65d22",
+        "8901000000000000000000000000000000000000000000000000000000000000000000eca4a24766c52abd872590493256c4fbe598d9b80d3f2917b94eeacadf3f3206000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "eca4a24766c52abd872590493256c4fbe598d9b80d3f2917b94eeacadf3f3206", &[
+            "0004c8000000200100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f000000000000002000000000000000",
+            "0004c8000000202100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f000000000000004000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "3706ff6f7d467b87a9e80300009afcc7f98ff9cddc99a30da9978ba691fba11d316b153f75cd91c83d91608a80",
+            "370dc7217647a32b0be9030000b6f20189d0b7d4f18ca94ef685d7f30b550c2e6018a7779842c12df38767b36d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "3f300fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "7f1a0fff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "800006802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e8032df111592fa0f87351d234c9f7ae9286282f7cdab1b2449253c5b69c844dc7c8070aeb61a4379d2f5185732bdfb3733dea0b00da32ef7c151c1b06510ffd986f2",
+            "8102c00080b3a65dda42917991256da42a7b052e40fd1d52153fe6c5f82e760396f0c10df0545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950235a80da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb4574380a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c380c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de806bd76c0f970330ebe2de97d3094cf7e753a0091cb48db982f2650e43ddbf0289",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b3128047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d88802acfb3b599d1b868215a06fcae980b46d8dbe10f04540157ce37cb44853ca3a080ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc1480e9f9e7a07cc0b2946f0d62897d676e0c1ce612e8becf4af403624c148edf9856801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000804a16d24301a4948f7bee6e1ac09857cbd65954d7ef66e470ee69c0b2acf4a88180e5d71ae7eaeac1c57f58d8c5ea1d957eb5d3588a2776a03b69337c06f18de586",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c805582d5886a9a998be4112a3253c353c3ad0dda6d716260c74e7a1f9c9ea09d5b80e25f9b658d0367dbdbbd1b5a0067acdc8630a022f89ca3e1967f76cc81ed3fe0",
+        ])),
+    (9, ("27fe9c3f3a196df7f815a8cfced0056bc246de4b7bce180ae0a2706ddfeeaea7", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300004321a61fe4faa3b3331d8adb30c8eb142a3dfa24ba74eef93ad644b0c244f013",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780b2fe96923b5165e9a9a813db5b0a32e267bc02b1b5257cd638fe79ce3f73982a",
+        "890100000000000000000000000000000000000000000000000000000000000000000094cb48d2be8ea6714d50c33e00b427251e7ed1bdc065181968e54258c32f4ed8000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "94cb48d2be8ea6714d50c33e00b427251e7ed1bdc065181968e54258c32f4ed8", &[
+            "0004c8000000240100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000",
+            "0004c800000024250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f00000000000000400000000000000041000000000000004200000000000000430000000000000044000000000000004500000000000000460000000000000047000000000000004800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "3706ff6f7d467b87a9e8030000abd763ef95aecaa842b41e9dafd6c8e666cba0dffc8dd7461da837c9b170dc14",
+            "370dc7217647a32b0be9030000350cec8e648f947d0dead9f479d5dd9035db5490a100ac7777ffdcbd30cab6fd",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f3008a524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "3f300fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "800006802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80a10669c8d3a88b52a129c8f48d7f8230381e364ce482ef5d7d97da8a359b89ec80e6c2d1a20930608568dcaa8366116fa33c39cea00ce3039dc691feb1e813d69e",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "8102c000805efe362d8d1d6a162227bb02fabe7331fff53653c47072dbea406fc8d61b927b545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950235a80da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb4574380a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c380c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de8032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b3328047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d88802acfb3b599d1b868215a06fcae980b46d8dbe10f04540157ce37cb44853ca3a080ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc1480e9f9e7a07cc0b2946f0d62897d676e0c1ce612e8becf4af403624c148edf9856801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a80d45bb57113af471db92a5759f29121985c124dadb87627fdf9f091b1fdcb978a",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080ebd926647d8a74cb3c3b360cb68788c0704b287b8e2c0b2ea73a107847dc4030808c64eb919655d520e76edb5d6225937188e845560aa99eb078330c1bea032487",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c802c59455bbf128bbc7128092284e1997f09745434f4decd1e84d7d6e80927f26b8036de83f92317c052d1b7b8bb0701977ee11fb2701abba20f79704373b9eac16c",
+        ])),
+    (10, ("de99db1e22e191d16d0ecde126057f7e8ee00cf5a0449c2d92046c9d9c9110f3", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000da8bc80caaba7587d4314a4b9e9a1b800d5c682c98289cca9d5cacbe22aa5440",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780da161df3e35ffe9dff1f3a408a94f88d0f11ba6adb394c495cb16b083018392c",
+        "89010000000000000000000000000000000000000000000000000000000000000000006b9ebfcb10b72baac7e36abb8affc4f5a1c960501137ca4517d838b70c8f21cc000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "6b9ebfcb10b72baac7e36abb8affc4f5a1c960501137ca4517d838b70c8f21cc", &[
+            "0004c8000000280100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f00000000000000200000000000000021000000000000002200000000000000230000000000000024000000000000002500000000000000260000000000000027000000000000002800000000000000",
+            "0004c80000002829000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f000000000000005000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "3706ff6f7d467b87a9e80300002a7397d56afd74b907f9ecbb2e50c26b0ee115ff957d23293e39281a8056b2b3",
+            "370dc7217647a32b0be9030000474b0fed5155cac910cf831a2aac89db539389ceac0997a25e43b32774ec54a2",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f3008a524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f300a40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "3f300fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a04c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "7f1a0bcb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "800006802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80bdb9f30a49f83987e37e99563a7fb61170b9622fcaaf8e10bb1f26133a48c00780cb1db5fc2ffda60ac36b04ca7fd6cf2d015328156c50846b3d6e14b7ab238e93",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "8102c00080aa80d9663b376491fbd5ff222671a52d824a9b7756b310dae8b692a16fb40c90545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950275a80da1c0ba58b8759c5d60902a4d017e6394cc49e6df2dee618fab83deb5eb4574380a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda01891980087802a104bb40b66ebe9db95af5beedb6fbe00a9f62389603c60e73be1bfb6447fe780ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c380c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de8032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d88802acfb3b599d1b868215a06fcae980b46d8dbe10f04540157ce37cb44853ca3a080ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc1480e9f9e7a07cc0b2946f0d62897d676e0c1ce612e8becf4af403624c148edf985680f390832e5aec9d549d878ab2dac9c0184d178341ea47c0e92b9e5c2339638d4b801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a80d45bb57113af471db92a5759f29121985c124dadb87627fdf9f091b1fdcb978a",
+            "9f0ec5070d609dd3497f72bde07fc96ba090008017a379b73ded5329ea619094dade8749fee27c769bb58c3cf78ef9aa6d6d0ba7802baae4fcd85139fa8abfcec974913d5f6b3ee011464c0d0bb31df2dd30ec9c0c",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c808b21e1cd811d5ba9778ae329ad6199546436b80d9e01b5dc7e3274ee42bcc9a98055f2ee823d63a47eb541c1598a482eb3ee9f1aaa0bf1287db0d48194fef9600b",
+        ])),
+    (11, ("5c86c23f1341245df1bbcccf2435379f2d5b2a1a2e04d7d6de10f2497dd118a9", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000f92ac95d7b8e302a28796cd0dbea7feaacc153e3d825255ee92001db8d15b847",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e17809541aef09632a85198a3787ef154a96b775f5165f44b4fa90f59da690aa865cc",
+        "890100000000000000000000000000000000000000000000000000000000000000000011b107ae9c2365484edf64676a2acdeecdfa6b5c2f81af6659082ef0eda28f92000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "11b107ae9c2365484edf64676a2acdeecdfa6b5c2f81af6659082ef0eda28f92", &[
+            "0004c80000002c0100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c00000000000000",
+            "0004c80000002c2d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f00000000000000500000000000000051000000000000005200000000000000530000000000000054000000000000005500000000000000560000000000000057000000000000005800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "3706ff6f7d467b87a9e80300006d76e28f6972a387832100de3dc1cd910fda2a4514e95c3d73d6f414efe83c65",
+            "370dc7217647a32b0be903000092748a66fed8f163235eaf34141bea7094b86b2b071918a7fc22730f895f2a3d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f30041148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f3008a524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f300a40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "7f1a0bcb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "800006802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80a57f000340a2bce3328b42d7ab317c6dfcacc9a89cafb6bc9b6bbfef758e381c8004aacfe11dbb572cbd87f6c70fc97044908d458954c19abbce159bca341fdce3",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "8102c00080994591f7ea6ee78b2cf35b3946c78aefe298dd14480021d6a1227d4533afe113545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950275a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda01891980087802a104bb40b66ebe9db95af5beedb6fbe00a9f62389603c60e73be1bfb6447fe780ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce033080ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c380c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de8032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d88802acfb3b599d1b868215a06fcae980b46d8dbe10f04540157ce37cb44853ca3a080ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed2180f390832e5aec9d549d878ab2dac9c0184d178341ea47c0e92b9e5c2339638d4b801ba296cae694c557feff8a838e77261eab7cfe4499f51f98bf5da7dd0574298a80d45bb57113af471db92a5759f29121985c124dadb87627fdf9f091b1fdcb978a",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080419db82bdaa9cc47f385f21238db7bab429e92b076a94b2f825da9852bbce9dd8017409549c18103af3195128e3be039e24af38e5e6182ed6917cc72a7d5c56ae8",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c807f62c11893282dd490d066840856a7097d0adc02789cfb6c30130eb52c95577280abd74d1c69a041ab3b6a6f7a1b7fa361572f833d7fb40867720f77f7adb9f2c8",
+        ])),
+    (12, ("99d58c83a11de6e0bdb271362e0547d3b76564c010b58ca2f95c4a6ec7691bf5", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300002699b82cfa20b86b2bedf8c345f955ed65149fa0c0acefb8215fbed2f00cb988",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780f0c816f5baa90ebafa1ae132425c397d921d18e977d6f802b05782a1d64b1e20",
+        "89010000000000000000000000000000000000000000000000000000000000000000007fd2d129c9a61880be5fa6979728f79840d2255809fe3b01efe037069659451b000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "7fd2d129c9a61880be5fa6979728f79840d2255809fe3b01efe037069659451b", &[
+            "0004c8000000300100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f000000000000003000000000000000",
+            "0004c8000000303100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f000000000000006000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "3706ff6f7d467b87a9e8030000a942082c7216fe226b041774ab12f323ac560f31afcad741ffe4640a3c379022",
+            "370dc7217647a32b0be9030000659ac1a97edadd81e5e7d9ca1726d7707a2df2296b059604191fb6ac245f7c54",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f3008a524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f300a40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "7f1a0bcb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f1a0c2c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "800006802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80adb5fe2e77d91ebb3c41e165fc5b22f6f082e463c3784af5ce99f75c7d7ed6e780264e1ad1d1d911e8de78f4066131ab88157c5fd429a02ca167ae31d3dfe5826e",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c00080ee93104ebdf5febe0d90ba388175eb3e8dca752dc732c71e78fd55dd3e27ed7b545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950675a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda01891980087802a104bb40b66ebe9db95af5beedb6fbe00a9f62389603c60e73be1bfb6447fe780ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce03308039fa796684c8c10b5b9b9e269039f1394ed4a6ebe3e505dbb00f4f8164a9199780ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c380c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de8032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d88802acfb3b599d1b868215a06fcae980b46d8dbe10f04540157ce37cb44853ca3a080ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed2180f390832e5aec9d549d878ab2dac9c0184d178341ea47c0e92b9e5c2339638d4b803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080d45bb57113af471db92a5759f29121985c124dadb87627fdf9f091b1fdcb978a",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000804c4fd37238ddb8b813f55d866859db03faf271cae0ea86e7d6b6303e56d7438c800ca7607666230c0c2c6e0853b33fbb1e8d377a7105248a378d2f257dba7d4418",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80203b555852d6caa579bcf887c6fb95331fcf8b796905150f79652529430c545480113a89910e5a117ab12363447e9a6c4cd01d891344e24aef7d80b7de6082ea1a",
+        ])),
+    (13, ("30062d19cd864e1cfe773a337a9d645a68a918652fc777d6682a67fad8ac1452", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300008ee4eddeeda9d840af9a09562ff1347e63c78bbd998a5ed1111ed89b15c54384",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780eeef1beee858b4f5b8e8e7c1da4ec6df46c5d3a61105b9c7834dd1e18b969607",
+        "89010000000000000000000000000000000000000000000000000000000000000000003c5bee702eb0dc97ac9853042b83a1ab600c644d75369bd5389ee4022673de12000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "3c5bee702eb0dc97ac9853042b83a1ab600c644d75369bd5389ee4022673de12", &[
+            "0004c8000000340100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000",
+            "0004c800000034350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f00000000000000600000000000000061000000000000006200000000000000630000000000000064000000000000006500000000000000660000000000000067000000000000006800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "3706ff6f7d467b87a9e80300005f6b1fb9a8607f1a9e1ca255b3f6337e1c90891dc9dce5eeca937f0b220a6fbf",
+            "370dc7217647a32b0be9030000348e739e9acc147976c00572dd91de8bce950208d95d2597f0f6ef25e52cd031",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f3008a524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f300a40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1913021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a098611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "7f1a0c2c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b805594ff2a161a8405368a71b6243c6a902d97f878ff6b00451349c4f72442212b",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e806aaba5672672c5266bab1910093856ea1b74d32a8102b19eb126ba5c75e0bf62802e43f50c314cc7c6134dd048b77faf8ae0d882ce612ea7e4775738f0766c80ac",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c00080dacfb4a985c7ca2e9f96811ebc70d0eee2ab95392f5bc6fdc82c2b31354549fd545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950675a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780ffd03f602b0398ae696f24f9edc9875c31d4502366dad1056172841d3d484ba080ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce03308039fa796684c8c10b5b9b9e269039f1394ed4a6ebe3e505dbb00f4f8164a9199780ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c380c9687a12cea1e5d161685957cfd51116e0a5695f4697c809ca8a10f1773916de8032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed2180f390832e5aec9d549d878ab2dac9c0184d178341ea47c0e92b9e5c2339638d4b803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080d45bb57113af471db92a5759f29121985c124dadb87627fdf9f091b1fdcb978a",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080fad5df1fd0f046d4fb4222ec9d593d9d1e11d75979d91c100cbee14390ba0ca380c9fd51f0a18639858224b7078ade315e8ecfc6700603d4d9d5fc76c8a23fffdf",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c800eb5b392d775ec841863f0f0c75831da60e31c89464491b26738dcecde09321380906a8f58f1a7d5ef215c1ce7373ae348f9154e5511fbab0c8ead3bb0c52c239e",
+        ])),
+    (14, ("ac28368de761ae8264c6996d80d9cedfec5e7aaa4c34286b591c454225dab65c", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000dbd7ad7a37f42f8d4ec20e5d6d632f3d3bb075accb479ac2fce0cf0bbd1fe049",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e17805f0518e230413110f1f785144f65902e13ff1ac3cf32fbba50717f038400fd6e",
+        "8901000000000000000000000000000000000000000000000000000000000000000000ce195bb5f6a8a93ee4898a580ab8686e51f6e10688d8182d83a05f5cf9146ca0000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "ce195bb5f6a8a93ee4898a580ab8686e51f6e10688d8182d83a05f5cf9146ca0", &[
+            "0004c8000000380100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f00000000000000300000000000000031000000000000003200000000000000330000000000000034000000000000003500000000000000360000000000000037000000000000003800000000000000",
+            "0004c80000003839000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f0000000000000060000000000000006100000000000000620000000000000063000000000000006400000000000000650000000000000066000000000000006700000000000000680000000000000069000000000000006a000000000000006b000000000000006c000000000000006d000000000000006e000000000000006f000000000000007000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e",
+            "3706ff6f7d467b87a9e8030000a3a88cccd99e00871f32f7150d51517856a2d4adb4ffea24572e739e47cf52d6",
+            "370dc7217647a32b0be9030000083bcb3b77ebe868b17a094dcc3649e8ee2ff555a34c53587eac88606ae737b2",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2f429c1c780b322c8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d715145707ee9a1c3233407679f936d6c8a364ffc7db392854d88d2401d928d67",
+            "3f2fa524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300a40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1913021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f1952d0af9e064d996e6d6273803500000000000000360000000000000037000000000000003800000000000000808d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f198611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "7f1a0c2c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b805594ff2a161a8405368a71b6243c6a902d97f878ff6b00451349c4f72442212b",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "80040180a4c74d45f24148d25beccf913f84b0472ec2af425f7f650e4d8af51a7d5db1fc809f26f601edc1cb777f8b15c834247c6739473359bc0cdca8b2189ae76e1b75bc",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e8011f9f8b7f83157bb371b08fd44dc58ecb06b0625911b73829bc14f2e53e2fe5680897c24bb5ddd89fae2ecb8f02fb958743367aee08c1a4e6225f9ef1a02216902",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8040028051f9f69e801d4e0e1a9bb23701dcf1b80e9746557651039d96844cbf7991396b80d8f9357576ccb1d8b4e346906a6768195f6af383205e138ea351e4d291df0a18",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c00080a6dd3d95ccbe43041f036e1b5750106247c0c7aa23cdbfa8e5cf0dc7d77ac65d545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950675a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780ffd03f602b0398ae696f24f9edc9875c31d4502366dad1056172841d3d484ba080ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce03308039fa796684c8c10b5b9b9e269039f1394ed4a6ebe3e505dbb00f4f8164a9199780ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c3800e6065af91dcbc07b174c9e001f972ca7f17c13c8b21cb35bcb8c3dad4bbc0308032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed2180f390832e5aec9d549d878ab2dac9c0184d178341ea47c0e92b9e5c2339638d4b803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080744933aca8cfd39f393293fd157e19db313d8252e9694c2f0e9db3ad04199169",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080165f7d14bc99165e7bd088bcf9c8fafbeeb5634d1708e5f28e4e0cee1932d349803d9cd587610f6e815c27f9100b22f0c67de392f2c0e38d4a7bb24176b860ce11",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c807659661f09473f17fdc17f2f2f20561cc229eaf33ec150c32c688cee68f3f5ce807342dc9faa81c5683a6d273a531a3097f8bb3fba9435bb3ddc7a0a8104598870",
+        ])),
+    (15, ("9ebe941c278512577a886154b3e9a0e04c9a8d1faa1bde49c7abfd7a91597d61", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e803000040f4be6a8c1e47f402d5ab76aff47d6da634babff3cbaf6bc005f56d31fd990c",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780cc70cd77eff3707287d2111d2ba2a03740ccf784fc451385513817f5801d5038",
+        "8901000000000000000000000000000000000000000000000000000000000000000000fcdfc86788827bc13bfd63b1657ba458c4d41e7dd76fe27b165fb3f2230919a3000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "fcdfc86788827bc13bfd63b1657ba458c4d41e7dd76fe27b165fb3f2230919a3", &[
+            "0004c80000003c0100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c00000000000000",
+            "0004c80000003c3d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f0000000000000060000000000000006100000000000000620000000000000063000000000000006400000000000000650000000000000066000000000000006700000000000000680000000000000069000000000000006a000000000000006b000000000000006c000000000000006d000000000000006e000000000000006f00000000000000700000000000000071000000000000007200000000000000730000000000000074000000000000007500000000000000760000000000000077000000000000007800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e",
+            "0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f",
+            "3706ff6f7d467b87a9e8030000c221a64dc741b634f6a4fa524743987625cb8cf87b2eee59a445276d261e9219",
+            "370dc7217647a32b0be9030000ae3badc58b28a9d13167efcb318dedec174d5826299565037551e2d5f69b7850",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2f40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f2f429c1c780b322c8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d715145707ee9a1c3233407679f936d6c8a364ffc7db392854d88d2401d928d67",
+            "3f2fa524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f2fb6eed5df1d19918e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e480bc7d8b83e666a142dc6376287c8ac862df81a22891178331924a9d5b8c21d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1803021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f180ad3d0bc6b59a66e6d62738039000000000000003a000000000000003b000000000000003c00000000000000808e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e",
+            "7f1952d0af9e064d996e6d6273803500000000000000360000000000000037000000000000003800000000000000808d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f198611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "7f1a0c2c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b80852410bbf70d7b2a4f83a3d9bf6fda6c6c288e3e7c6af21a1e5f1bdf55434df7",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "800204804565f8f0a3ba254e258ee3e02b642965952fb4d0d98db66c9885c871e3032ee6803648d5ac1a97401043e2c43b049035954ad13716cacb9fc6b9f66574cd4a0f6a",
+            "800300804b701cd8c852cc9c19134d5ea33a390d88065ef3b4bc911ceb99b01003f8c956806e74066173f779b5a53f41e624e11c07a97fc326969269dfbef6fa705eb26cfa",
+            "80040180a4c74d45f24148d25beccf913f84b0472ec2af425f7f650e4d8af51a7d5db1fc809f26f601edc1cb777f8b15c834247c6739473359bc0cdca8b2189ae76e1b75bc",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80931a842f09feaf179791fe8f7c198d62de8cf075c205b2c765a0c3a9308e85bd80a0b2ac45199b0073e7d9f219e70396c0b194dc93ca2dbe48de3cf048c31b900b",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8040028051f9f69e801d4e0e1a9bb23701dcf1b80e9746557651039d96844cbf7991396b80d8f9357576ccb1d8b4e346906a6768195f6af383205e138ea351e4d291df0a18",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c00080ed49f42f48cc9e871b71c0e9c2a430acaf732ab1ead98f617dbb9a7df3252271545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950675a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780449fcef31328cceb40ca303ff42412d84e13d009e9f8956dbac69b669e1e866e80ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce03308039fa796684c8c10b5b9b9e269039f1394ed4a6ebe3e505dbb00f4f8164a9199780ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c3800e6065af91dcbc07b174c9e001f972ca7f17c13c8b21cb35bcb8c3dad4bbc0308032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed2180cd07254b51c2670095e98de4e8e5696ea2b960296d9a3f3fbd6fa00373794c46803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080744933aca8cfd39f393293fd157e19db313d8252e9694c2f0e9db3ad04199169",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000800c552b8312f969a2fcd35eb883ffe6ef22edbe1383088f54aa261cb80b402c73804c9ba388c85df5a4a867468738459e1b6cbc3b4b564345cf5e6b451d70e67129",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80716c0e10427de1b9fd219b5e263c8c4f6e7ce25e0f7d1773a4656963f7cbd20080c64e5fcaed0484c1df2740a54ea204a4a0fed85b12bb37389e8fc9f48c657fa9",
+        ])),
+    (16, ("be816339860f6c50131f3c8f44c915140256040cb141d88508308c72b3876c2f", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300002802f2f898016e6f83c6d01dd715f219b00bb1379e9aa2295929c185ddafcd24",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e17803aee3460b13c91c1993a95e39ba03bfbd9a1f9b19811a78f5e676d4cd8557353",
+        "89010000000000000000000000000000000000000000000000000000000000000000006174473067ecfb9d6782e5bcbbb66ea36086895b09b6ebaf06558501d2820437000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "6174473067ecfb9d6782e5bcbbb66ea36086895b09b6ebaf06558501d2820437", &[
+            "0004c8000000400100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f000000000000004000000000000000",
+            "0004c8000000404100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f0000000000000060000000000000006100000000000000620000000000000063000000000000006400000000000000650000000000000066000000000000006700000000000000680000000000000069000000000000006a000000000000006b000000000000006c000000000000006d000000000000006e000000000000006f0000000000000070000000000000007100000000000000720000000000000073000000000000007400000000000000750000000000000076000000000000007700000000000000780000000000000079000000000000007a000000000000007b000000000000007c000000000000007d000000000000007e000000000000007f000000000000008000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e",
+            "0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f",
+            "10101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010",
+            "3706ff6f7d467b87a9e80300006f8067df436d054f9beb343e7bf55a7cbd1db95b0cfaa19fbd612a79e58d442b",
+            "370dc7217647a32b0be90300007f540ff8d5d4e618733c773ee21b19ea44ea582b30454178e4913c07f1f81b8e",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f2b559279eeefc58f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f40b4b018a72f42532ee3a469d97da2862d1af9bb5988c0a65def02d2c55a8c54",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2f40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f2f429c1c780b322c8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d715145707ee9a1c3233407679f936d6c8a364ffc7db392854d88d2401d928d67",
+            "3f2fa524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f2fb6eed5df1d19918e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e480bc7d8b83e666a142dc6376287c8ac862df81a22891178331924a9d5b8c21d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1803021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f180ad3d0bc6b59a66e6d62738039000000000000003a000000000000003b000000000000003c00000000000000808e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e",
+            "7f192c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "7f1952d0af9e064d996e6d6273803500000000000000360000000000000037000000000000003800000000000000808d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f198611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f198db58363e33a286e6d6273803d000000000000003e000000000000003f000000000000004000000000000000808f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b80852410bbf70d7b2a4f83a3d9bf6fda6c6c288e3e7c6af21a1e5f1bdf55434df7",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "8002108032fa2ff074158518071d94f6155836f3efeeeb9e36aac4432eda0dbda69a7f26809b9bdd91b8762ab911fcefe3d2b2c3aceca1fd08c93e11bcbae82654b41b6e61",
+            "800300804b701cd8c852cc9c19134d5ea33a390d88065ef3b4bc911ceb99b01003f8c956806e74066173f779b5a53f41e624e11c07a97fc326969269dfbef6fa705eb26cfa",
+            "80040180a4c74d45f24148d25beccf913f84b0472ec2af425f7f650e4d8af51a7d5db1fc809f26f601edc1cb777f8b15c834247c6739473359bc0cdca8b2189ae76e1b75bc",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80434ee16c5e095f53c7252021b1c1e5976623e8773d9fe87566270f7682be7733800263c15eca859b3b498a01ec5f5a5d9cf2610fd358d5567ed20c80afffd1f08d",
+            "800a04804565f8f0a3ba254e258ee3e02b642965952fb4d0d98db66c9885c871e3032ee680065cc13f7cef011119c0a58b0d187c62518b1cdd06e47b1a49e0641a1323d4b4803648d5ac1a97401043e2c43b049035954ad13716cacb9fc6b9f66574cd4a0f6a",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8040028051f9f69e801d4e0e1a9bb23701dcf1b80e9746557651039d96844cbf7991396b80d8f9357576ccb1d8b4e346906a6768195f6af383205e138ea351e4d291df0a18",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c000807d7f074614830fdff70662bb2fb2e333c43fbe32438732b8e775d3567184cf0b545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950675a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780449fcef31328cceb40ca303ff42412d84e13d009e9f8956dbac69b669e1e866e80ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce03308060fbe617e39d6f7a545855b9fe44671ff2464ca8dbad319540ee8f4f6ad1c1f480ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c3800e6065af91dcbc07b174c9e001f972ca7f17c13c8b21cb35bcb8c3dad4bbc0308032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3b33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b380143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed2180a00acd72ef07236ddbf30fb6c748a8419de12b3db99b2cb4905ed427507caa9f803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080744933aca8cfd39f393293fd157e19db313d8252e9694c2f0e9db3ad04199169",
+            "9f0ec5070d609dd3497f72bde07fc96ba090008063ac99921ffe107c45377bc2a304892a3310383298234f08d712d4dbd504c89a805935418226a654d610db51264948c43a422e3d73d29abfa97e3c12bc145cae3b",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80f6a52e16c83dd8292837016ad36b65c878dda5f266a8beba1c6f588fe1f215e38000de813f9255c05260c30224c9e30f670c359d592fdf83ac455e10df2be5be1d",
+        ])),
+    (17, ("512ae672e26fd75ee121aadea5683161b9754347795ee1a5744fc82c5b9fe895", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000cac7aa0ce21869617e2330d5190da017d6ef38a9946d7f99dd838346862be4e3",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780bedb7f7e1847deea911a97aa9661185a39a8506689774882402eaf5d8453ca3c",
+        "8901000000000000000000000000000000000000000000000000000000000000000000cdd97eb908124d4dfdd0497232c1411770293504f06e5524b12707c5d70b7b80000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "cdd97eb908124d4dfdd0497232c1411770293504f06e5524b12707c5d70b7b80", &[
+            "0004c8000000440100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000",
+            "0004c800000044450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f0000000000000060000000000000006100000000000000620000000000000063000000000000006400000000000000650000000000000066000000000000006700000000000000680000000000000069000000000000006a000000000000006b000000000000006c000000000000006d000000000000006e000000000000006f0000000000000070000000000000007100000000000000720000000000000073000000000000007400000000000000750000000000000076000000000000007700000000000000780000000000000079000000000000007a000000000000007b000000000000007c000000000000007d000000000000007e000000000000007f00000000000000800000000000000081000000000000008200000000000000830000000000000084000000000000008500000000000000860000000000000087000000000000008800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e",
+            "0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f",
+            "10101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010",
+            "11111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111",
+            "3706ff6f7d467b87a9e8030000d228d62c476523ade8a42e80e91067b8b49bfe3e43948d7448d4a077e916941f",
+            "370dc7217647a32b0be9030000d66320e6b461cd495f4566dcb26133f1a9e8ed479c183bff23957601ee0c16d2",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f2b559279eeefc58f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f40b4b018a72f42532ee3a469d97da2862d1af9bb5988c0a65def02d2c55a8c54",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2f40b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f2f429c1c780b322c8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d715145707ee9a1c3233407679f936d6c8a364ffc7db392854d88d2401d928d67",
+            "3f2fa524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f2fb6eed5df1d19918e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e480bc7d8b83e666a142dc6376287c8ac862df81a22891178331924a9d5b8c21d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300b1385017eff3d089090909090909090909090909090909090909090909090909090909090909090aa9c9b498ede5b1e11cc48ea17fb0e6a8c86e92070c0590bb469a2f7b7579479",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1803021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f180ad3d0bc6b59a66e6d62738039000000000000003a000000000000003b000000000000003c00000000000000808e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e",
+            "7f192c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "7f1952d0af9e064d996e6d6273803500000000000000360000000000000037000000000000003800000000000000808d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f198611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f198db58363e33a286e6d6273803d000000000000003e000000000000003f000000000000004000000000000000808f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a02f8a1d30bd7eda56e6d6273804100000000000000420000000000000043000000000000004400000000000000809090909090909090909090909090909090909090909090909090909090909090",
+            "7f1a08205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b80852410bbf70d7b2a4f83a3d9bf6fda6c6c288e3e7c6af21a1e5f1bdf55434df7",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "8002108032fa2ff074158518071d94f6155836f3efeeeb9e36aac4432eda0dbda69a7f26809b9bdd91b8762ab911fcefe3d2b2c3aceca1fd08c93e11bcbae82654b41b6e61",
+            "800300804b701cd8c852cc9c19134d5ea33a390d88065ef3b4bc911ceb99b01003f8c956806e74066173f779b5a53f41e624e11c07a97fc326969269dfbef6fa705eb26cfa",
+            "80040180a4c74d45f24148d25beccf913f84b0472ec2af425f7f650e4d8af51a7d5db1fc809f26f601edc1cb777f8b15c834247c6739473359bc0cdca8b2189ae76e1b75bc",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80be08aa9572de68fc8a7a46365e2b631f0c1c5242fac580eb835f2eb10d55bf678071658a9523ba285d2a026f46789ac38366e1081cf2ae80bde318dcbe4c92f918",
+            "800a04804565f8f0a3ba254e258ee3e02b642965952fb4d0d98db66c9885c871e3032ee680065cc13f7cef011119c0a58b0d187c62518b1cdd06e47b1a49e0641a1323d4b4803648d5ac1a97401043e2c43b049035954ad13716cacb9fc6b9f66574cd4a0f6a",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8040028051f9f69e801d4e0e1a9bb23701dcf1b80e9746557651039d96844cbf7991396b80d8f9357576ccb1d8b4e346906a6768195f6af383205e138ea351e4d291df0a18",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c00080c60fe92bc3689fc8f36d1f4c585d029230444c769fd44ba02b0d347ca413ee28545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950e75a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780449fcef31328cceb40ca303ff42412d84e13d009e9f8956dbac69b669e1e866e80ed85052cd1a95a76542d48e0a9f1cb60d6554498e8f9dcef11b6998446ce03308060fbe617e39d6f7a545855b9fe44671ff2464ca8dbad319540ee8f4f6ad1c1f4808092f5e96c276dba4d67658ab3fee224db9a30ea43bed38b7fa6ff11c344b51380ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c3800e6065af91dcbc07b174c9e001f972ca7f17c13c8b21cb35bcb8c3dad4bbc0308032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3f33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b38083a1c4edb4c200fde7dad4e79f0c98fb0fccb3f9196d74817319ac4b6668796d80143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed2180a00acd72ef07236ddbf30fb6c748a8419de12b3db99b2cb4905ed427507caa9f803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080744933aca8cfd39f393293fd157e19db313d8252e9694c2f0e9db3ad04199169",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080a46aaa1895b412c9bad6799d3a4561b82f7ac0690c7989c957b88f51125efb4580ba2a2ad6d305906dd97dd0bdd4a1ba2064cdd27f42feabe0d215aacce8db337d",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80d1b1f82f383fa896a265f3a991074f6909aebc14de7bcb9acacd6427761c1652806b232296426aba46004c3ed0a5c82744e742326fec3936694271dbb06425f63d",
+        ])),
+    (18, ("70b30daf23fa1035dddfad612ba87229fb940b4caca602d0a13a9ca010ad3320", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e80300004183eb4efd66edcd5d5c3e1501b75c3f8b5fb3021542beef27ce5e683db5235d",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780158953ebee90a36832d9e9fedf5178b3bf8500a370a9b4d7a56b5c637763092c",
+        "8901000000000000000000000000000000000000000000000000000000000000000000cdbe80bc3cfc77ae97b8fdaa27b99b88fb46502f40fbc440a336f824c2e831a3000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "cdbe80bc3cfc77ae97b8fdaa27b99b88fb46502f40fbc440a336f824c2e831a3", &[
+            "0004c8000000480100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f00000000000000400000000000000041000000000000004200000000000000430000000000000044000000000000004500000000000000460000000000000047000000000000004800000000000000",
+            "0004c80000004849000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f0000000000000060000000000000006100000000000000620000000000000063000000000000006400000000000000650000000000000066000000000000006700000000000000680000000000000069000000000000006a000000000000006b000000000000006c000000000000006d000000000000006e000000000000006f0000000000000070000000000000007100000000000000720000000000000073000000000000007400000000000000750000000000000076000000000000007700000000000000780000000000000079000000000000007a000000000000007b000000000000007c000000000000007d000000000000007e000000000000007f0000000000000080000000000000008100000000000000820000000000000083000000000000008400000000000000850000000000000086000000000000008700000000000000880000000000000089000000000000008a000000000000008b000000000000008c000000000000008d000000000000008e000000000000008f000000000000009000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e",
+            "0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f",
+            "10101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010",
+            "11111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111",
+            "12121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212",
+            "3706ff6f7d467b87a9e8030000e0656a8f2a965177c2041eb0f03346447e40d6d9ce9a16ef90e40bd2edb794d7",
+            "370dc7217647a32b0be9030000764f1ad68766cf3f4a2ffe4c34f753826a3418754345cc831ba9b58423ffe0f0",
+            "3f2e00b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f2e07596a9834bdf39191919191919191919191919191919191919191919191919191919191919191df10db0f4894225c5db0805cd1f964d42899a5c589f2a3c01deb82a38b2f89d8",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f2b559279eeefc58f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f40b4b018a72f42532ee3a469d97da2862d1af9bb5988c0a65def02d2c55a8c54",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2f429c1c780b322c8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d715145707ee9a1c3233407679f936d6c8a364ffc7db392854d88d2401d928d67",
+            "3f2fa524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f2fb6eed5df1d19918e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e480bc7d8b83e666a142dc6376287c8ac862df81a22891178331924a9d5b8c21d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300b1385017eff3d089090909090909090909090909090909090909090909090909090909090909090aa9c9b498ede5b1e11cc48ea17fb0e6a8c86e92070c0590bb469a2f7b7579479",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "7f1803021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f180ad3d0bc6b59a66e6d62738039000000000000003a000000000000003b000000000000003c00000000000000808e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e",
+            "7f19205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f192c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "7f1952d0af9e064d996e6d6273803500000000000000360000000000000037000000000000003800000000000000808d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f198611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f198db58363e33a286e6d6273803d000000000000003e000000000000003f000000000000004000000000000000808f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19f8daf856b19f296e6d6273804500000000000000460000000000000047000000000000004800000000000000809191919191919191919191919191919191919191919191919191919191919191",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a02f8a1d30bd7eda56e6d6273804100000000000000420000000000000043000000000000004400000000000000809090909090909090909090909090909090909090909090909090909090909090",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "80000380e970c702c194536de1e3834d7c95e0a66a037332b63bf0403258f21058677dbd80498276877a221e06a042712c6ab3ebb10b6b8932af757b149055747dbbc259bf",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b80852410bbf70d7b2a4f83a3d9bf6fda6c6c288e3e7c6af21a1e5f1bdf55434df7",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "8002108032fa2ff074158518071d94f6155836f3efeeeb9e36aac4432eda0dbda69a7f26809b9bdd91b8762ab911fcefe3d2b2c3aceca1fd08c93e11bcbae82654b41b6e61",
+            "800300804b701cd8c852cc9c19134d5ea33a390d88065ef3b4bc911ceb99b01003f8c956806e74066173f779b5a53f41e624e11c07a97fc326969269dfbef6fa705eb26cfa",
+            "80040180a4c74d45f24148d25beccf913f84b0472ec2af425f7f650e4d8af51a7d5db1fc809f26f601edc1cb777f8b15c834247c6739473359bc0cdca8b2189ae76e1b75bc",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80a695cf35c66566abf08b924a7680f18ec756038fa48ec010f3f1673fd8b641c280cb65991a03c2272baeeb6cc59f95c52fb788a70d8ad76758327afbe1771ad6d8",
+            "800a04804565f8f0a3ba254e258ee3e02b642965952fb4d0d98db66c9885c871e3032ee680065cc13f7cef011119c0a58b0d187c62518b1cdd06e47b1a49e0641a1323d4b48029d568193b28cdefc73591bc91926007a0f6b38e7d2874cfb050747d68065330",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8040028051f9f69e801d4e0e1a9bb23701dcf1b80e9746557651039d96844cbf7991396b80d8f9357576ccb1d8b4e346906a6768195f6af383205e138ea351e4d291df0a18",
+            "8080808049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a586504800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000802e818e994412fd5206e82abe22c075b8e369c99986910fa789f11d2d5db4af0c803d1c4fdd7df7fca3da058d7f00183c1b46a3969798fc7ee0d1b6fb8e8ea5309a",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c00080607c9c629cbce5d6e53a54a989a25cd2c6209ffc35df7b3220cf0508af31c259545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950e75a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780449fcef31328cceb40ca303ff42412d84e13d009e9f8956dbac69b669e1e866e807d5e64ea8403981992ef203d545c5a49e6ca89dcd43b2d76ecedc36e3c72744e8060fbe617e39d6f7a545855b9fe44671ff2464ca8dbad319540ee8f4f6ad1c1f4808092f5e96c276dba4d67658ab3fee224db9a30ea43bed38b7fa6ff11c344b51380ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c3800e6065af91dcbc07b174c9e001f972ca7f17c13c8b21cb35bcb8c3dad4bbc0308032a7288f8386504086d1c8c544ed0ee064a12b71a2bf42b8686bac7aba25d01d",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3f33a8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b38083a1c4edb4c200fde7dad4e79f0c98fb0fccb3f9196d74817319ac4b6668796d80143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed21800d1677a2fc095ef1f0e99e9e9e1056abc53ae23696d42694779bf9a8dba1b10c803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080744933aca8cfd39f393293fd157e19db313d8252e9694c2f0e9db3ad04199169",
+            "9f0ec5070d609dd3497f72bde07fc96ba09000805aaf174174a90617bcb25417e349ee35725943ec4528f6f6850ed497ae371d1e8061436b3b166f8a2c75ffc4eb64bdb9d174bf026c5fa26d13f20c33755e9f84fd",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80a0ebb81ac29aa19ed6d969c50572588bf3bdf87859e3e13f3b425ab1bfe264fb807ee2ce0ea6c4afa7f707643c09f74059a52a06d2a917ae7968dd562e111c06d8",
+        ])),
+    (19, ("39ac93f059ac7a1dea3967ce8dadd86c161968dd94a103e6e2c00e9a2ba1bb3e", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000e4066ad0151fa085de82348f82f888535771c00b325d475ccd76b63c91894888",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780b9c1fac7f7357f4aafb13a021ee06aefddc53a169264ae43a75cf1e2c2bdc701",
+        "8901000000000000000000000000000000000000000000000000000000000000000000a9537682908519cba962666a66ea546868733d5dcaf9b1397f8fa323a9b78d0e000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "a9537682908519cba962666a66ea546868733d5dcaf9b1397f8fa323a9b78d0e", &[
+            "0004c80000004c0100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c00000000000000",
+            "0004c80000004c4d000000000000004e000000000000004f0000000000000050000000000000005100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f0000000000000060000000000000006100000000000000620000000000000063000000000000006400000000000000650000000000000066000000000000006700000000000000680000000000000069000000000000006a000000000000006b000000000000006c000000000000006d000000000000006e000000000000006f0000000000000070000000000000007100000000000000720000000000000073000000000000007400000000000000750000000000000076000000000000007700000000000000780000000000000079000000000000007a000000000000007b000000000000007c000000000000007d000000000000007e000000000000007f0000000000000080000000000000008100000000000000820000000000000083000000000000008400000000000000850000000000000086000000000000008700000000000000880000000000000089000000000000008a000000000000008b000000000000008c000000000000008d000000000000008e000000000000008f00000000000000900000000000000091000000000000009200000000000000930000000000000094000000000000009500000000000000960000000000000097000000000000009800000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e",
+            "0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f",
+            "10101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010",
+            "11111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111",
+            "12121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212",
+            "13131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313",
+            "3706ff6f7d467b87a9e8030000f2865a369a15c5939844d8c0a243ece383df3d2152c9ea7f28e4a3cfe69b9f8e",
+            "370dc7217647a32b0be9030000ce5dd70ef0ff26cf89b5069d958b0042b1976e6b3d7211f16948641b02506494",
+            "3f2e00b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f2e07596a9834bdf39191919191919191919191919191919191919191919191919191919191919191df10db0f4894225c5db0805cd1f964d42899a5c589f2a3c01deb82a38b2f89d8",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f2b559279eeefc58f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f40b4b018a72f42532ee3a469d97da2862d1af9bb5988c0a65def02d2c55a8c54",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2f429c1c780b322c8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d715145707ee9a1c3233407679f936d6c8a364ffc7db392854d88d2401d928d67",
+            "3f2fa524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f2fb6eed5df1d19918e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e480bc7d8b83e666a142dc6376287c8ac862df81a22891178331924a9d5b8c21d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300b1385017eff3d089090909090909090909090909090909090909090909090909090909090909090aa9c9b498ede5b1e11cc48ea17fb0e6a8c86e92070c0590bb469a2f7b7579479",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "3f300cd70c27290b6c9d92929292929292929292929292929292929292929292929292929292929292923111c2e32bda05f5645cef5f842e4ca17089bf652bc81dbad5e222dc3adf924c",
+            "7f1803021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f180ad3d0bc6b59a66e6d62738039000000000000003a000000000000003b000000000000003c00000000000000808e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e",
+            "7f19205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f192c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "7f1952d0af9e064d996e6d6273803500000000000000360000000000000037000000000000003800000000000000808d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f198611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f198db58363e33a286e6d6273803d000000000000003e000000000000003f000000000000004000000000000000808f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19dd93e673fca1286e6d62738049000000000000004a000000000000004b000000000000004c00000000000000809292929292929292929292929292929292929292929292929292929292929292",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19f8daf856b19f296e6d6273804500000000000000460000000000000047000000000000004800000000000000809191919191919191919191919191919191919191919191919191919191919191",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a02f8a1d30bd7eda56e6d6273804100000000000000420000000000000043000000000000004400000000000000809090909090909090909090909090909090909090909090909090909090909090",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "80000380e970c702c194536de1e3834d7c95e0a66a037332b63bf0403258f21058677dbd80498276877a221e06a042712c6ab3ebb10b6b8932af757b149055747dbbc259bf",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b80852410bbf70d7b2a4f83a3d9bf6fda6c6c288e3e7c6af21a1e5f1bdf55434df7",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "8002108032fa2ff074158518071d94f6155836f3efeeeb9e36aac4432eda0dbda69a7f26809b9bdd91b8762ab911fcefe3d2b2c3aceca1fd08c93e11bcbae82654b41b6e61",
+            "800300804b701cd8c852cc9c19134d5ea33a390d88065ef3b4bc911ceb99b01003f8c956806e74066173f779b5a53f41e624e11c07a97fc326969269dfbef6fa705eb26cfa",
+            "80040180a4c74d45f24148d25beccf913f84b0472ec2af425f7f650e4d8af51a7d5db1fc809f26f601edc1cb777f8b15c834247c6739473359bc0cdca8b2189ae76e1b75bc",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80ae39b79ab9d2656510dce68fe50e16e37d3442a450bb166cb5454671ddd78dce801656a2126fec498a16c3c35cc332fb3a3727a9587a4100eae71155901f5a1c7a",
+            "800a04804565f8f0a3ba254e258ee3e02b642965952fb4d0d98db66c9885c871e3032ee680065cc13f7cef011119c0a58b0d187c62518b1cdd06e47b1a49e0641a1323d4b48029d568193b28cdefc73591bc91926007a0f6b38e7d2874cfb050747d68065330",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8040028051f9f69e801d4e0e1a9bb23701dcf1b80e9746557651039d96844cbf7991396b80d8f9357576ccb1d8b4e346906a6768195f6af383205e138ea351e4d291df0a18",
+            "8080908049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a5865048036bf105fa0b4f8728255e93c193604ac0873b28de1850882b831f677ff58a799800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000802e818e994412fd5206e82abe22c075b8e369c99986910fa789f11d2d5db4af0c803d1c4fdd7df7fca3da058d7f00183c1b46a3969798fc7ee0d1b6fb8e8ea5309a",
+            "809000804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3",
+            "8102c00080327c0681de29dabba2f98249d74a582193de07061ba7065dee41db277e2cfcec545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950e75a80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780449fcef31328cceb40ca303ff42412d84e13d009e9f8956dbac69b669e1e866e807d5e64ea8403981992ef203d545c5a49e6ca89dcd43b2d76ecedc36e3c72744e8060fbe617e39d6f7a545855b9fe44671ff2464ca8dbad319540ee8f4f6ad1c1f4808092f5e96c276dba4d67658ab3fee224db9a30ea43bed38b7fa6ff11c344b51380ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca388054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c3800e6065af91dcbc07b174c9e001f972ca7f17c13c8b21cb35bcb8c3dad4bbc030804b4311fe39b3534486c164d242da59c6b78583bfc12ae64f262e43a0d2ce5cd6",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3f3ba8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b38083a1c4edb4c200fde7dad4e79f0c98fb0fccb3f9196d74817319ac4b6668796d80143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed21800d1677a2fc095ef1f0e99e9e9e1056abc53ae23696d42694779bf9a8dba1b10c803a89d433d542d250b83bf5719bf02ae787c8a44a769f5332cd5c334115463d9080744933aca8cfd39f393293fd157e19db313d8252e9694c2f0e9db3ad04199169805e95ff00feb14e93b616c3b996fb353403e5960b41a132ae896d5d3e19956a48",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080a34eb78a0209f6d824b7ea626661cd27a806f71e7491eaf5ac7f2920f43e0169801368b7fd0384246eab0bf1e2524aad4647599dade7b5d3fed326db0c1b552318",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c8089f01360c8142cbf34425508fb6e339606d7c2a3fefa35ba9a508c12fd89cdbe80f451f902ca21877b9805f33add769fa74cfb492cdd0f641461a565b160d92ac8",
+        ])),
+    (20, ("77dabcc748450ffdfc67646f8b900085ff2ff68b406575126ec2f1807c460b91", &[
+        "0000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000100",
+        "3f380d710b30bd2eab0352ddcc26417aa1941b3c252fcb29d88eff4f3de5de4476c3b6ff6f7d467b87a9e8030000573b34fa144263dde800a8792245c0cef6f0f6374285ddddacb584a88ae0fd58",
+        "3f380fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa1940a31c34bd88c539ec80000005c180c7bb05f36677412adef2e2cf99db165d2bf85a5c897e690efffe5bf474c",
+        "5f0a414cb008e0e61e46722aa60abdd672800707070707070707070707070707070707070707070707070707070707070707",
+        "800a10806461d170ca267d24378a073877e41af74bb4b6eed43b7db668d9aa37a1e7caa2805f2fa434a8c3ecaf109e43ee58d0e9dc1871f27af1a0e91b6f9cf0e01d365e1780621445a2463edc37fa38861b1bc95d0c688eb846d3091fe1ca58355b7b5e9aae",
+        "8901000000000000000000000000000000000000000000000000000000000000000000be9bb375ad7f123c8325774a0a6b1b031e02b72ca645c4cf3312a3169d68a9de000000000000000000000000000000000000000000000000000000000000000000",
+        "9f0cb6f36e027abb2091cfb5110ab5087f8900685f06155b3cd9a8c9e5e9a23fd5dc13a5ed206400000000000000685f08316cbf8fa0da822a20ac1c55bf1be3200100000000000000801abc380359521fc15e852507d296a9a4329df1ddc4daa5df1a3f504fd810a5df",
+    ]), (
+         "be9bb375ad7f123c8325774a0a6b1b031e02b72ca645c4cf3312a3169d68a9de", &[
+            "0004c8000000500100000000000000020000000000000003000000000000000400000000000000050000000000000006000000000000000700000000000000080000000000000009000000000000000a000000000000000b000000000000000c000000000000000d000000000000000e000000000000000f0000000000000010000000000000001100000000000000120000000000000013000000000000001400000000000000150000000000000016000000000000001700000000000000180000000000000019000000000000001a000000000000001b000000000000001c000000000000001d000000000000001e000000000000001f0000000000000020000000000000002100000000000000220000000000000023000000000000002400000000000000250000000000000026000000000000002700000000000000280000000000000029000000000000002a000000000000002b000000000000002c000000000000002d000000000000002e000000000000002f0000000000000030000000000000003100000000000000320000000000000033000000000000003400000000000000350000000000000036000000000000003700000000000000380000000000000039000000000000003a000000000000003b000000000000003c000000000000003d000000000000003e000000000000003f0000000000000040000000000000004100000000000000420000000000000043000000000000004400000000000000450000000000000046000000000000004700000000000000480000000000000049000000000000004a000000000000004b000000000000004c000000000000004d000000000000004e000000000000004f000000000000005000000000000000",
+            "0004c8000000505100000000000000520000000000000053000000000000005400000000000000550000000000000056000000000000005700000000000000580000000000000059000000000000005a000000000000005b000000000000005c000000000000005d000000000000005e000000000000005f0000000000000060000000000000006100000000000000620000000000000063000000000000006400000000000000650000000000000066000000000000006700000000000000680000000000000069000000000000006a000000000000006b000000000000006c000000000000006d000000000000006e000000000000006f0000000000000070000000000000007100000000000000720000000000000073000000000000007400000000000000750000000000000076000000000000007700000000000000780000000000000079000000000000007a000000000000007b000000000000007c000000000000007d000000000000007e000000000000007f0000000000000080000000000000008100000000000000820000000000000083000000000000008400000000000000850000000000000086000000000000008700000000000000880000000000000089000000000000008a000000000000008b000000000000008c000000000000008d000000000000008e000000000000008f0000000000000090000000000000009100000000000000920000000000000093000000000000009400000000000000950000000000000096000000000000009700000000000000980000000000000099000000000000009a000000000000009b000000000000009c000000000000009d000000000000009e000000000000009f00000000000000a000000000000000",
+            "01010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101",
+            "02020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+            "03030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303030303",
+            "04040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404040404",
+            "05050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505050505",
+            "06060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606060606",
+            "07070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707",
+            "08080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808080808",
+            "09090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909090909",
+            "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c",
+            "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e0e",
+            "0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f",
+            "10101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010101010",
+            "11111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111",
+            "12121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212121212",
+            "13131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313131313",
+            "14141414141414141414141414141414141414141414141414141414141414141414141414141414141414141414141414141414141414141414141414141414",
+            "3706ff6f7d467b87a9e803000046fdf445df4babcd813be425f091c6be50a0bb66f2ebd591f75be10a6510f3fa",
+            "370dc7217647a32b0be90300008c5262ee15ab4f2d2b44b292b8d6f1ee928e99f91882476e08d161d8166493b9",
+            "3f2e00b6ab0c4d5c6e8989898989898989898989898989898989898989898989898989898989898989f3cabba24e70c642e0af4e1f2b9af9e8456a9dae33ccf1f2f42eb45882e6019e",
+            "3f2e07596a9834bdf39191919191919191919191919191919191919191919191919191919191919191df10db0f4894225c5db0805cd1f964d42899a5c589f2a3c01deb82a38b2f89d8",
+            "3f2f1148b28381e453828282828282828282828282828282828282828282828282828282828282828252c5feaf879f1dca6878b51e619da6833d6bdf2a82814c32ce5abf9255eaf5d7",
+            "3f2f2b559279eeefc58f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f40b4b018a72f42532ee3a469d97da2862d1af9bb5988c0a65def02d2c55a8c54",
+            "3f2f3da11a64c8f2018b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b048ba2bd678c041572ff2b00c946ddb7c4df51567615bb83e196eb2d5bf12767",
+            "3f2f429c1c780b322c8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d715145707ee9a1c3233407679f936d6c8a364ffc7db392854d88d2401d928d67",
+            "3f2f907f07474c382a9393939393939393939393939393939393939393939393939393939393939393045c07cfa60a095e2af0316074b3369dedf6f05ef168d5027f10a9858e9d0043",
+            "3f2fa524c559e2870a88888888888888888888888888888888888888888888888888888888888888883168cd54a13e7084aa783c480778e9f2f3f44cd25ab0b9fc8fc74db56b30928d",
+            "3f2fb6eed5df1d19918e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e480bc7d8b83e666a142dc6376287c8ac862df81a22891178331924a9d5b8c21d",
+            "3f2fbd90c3ef5a47c48686868686868686868686868686868686868686868686868686868686868686304320938b49aecf52b6ba2f5cee0b94120542edd72bfd3536736fff1e5a845e",
+            "3f2fc961acd549fe178c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c19b4db93357cda9f10330fd7bd75193d598e45f9eeda2a8080a02fd5ccff6c52",
+            "3f2fdc76480fc22e778585858585858585858585858585858585858585858585858585858585858585cb015a770a1aae090406aa3114d3cbeec2f8d64ae2ed14305d4b1726412e7ac7",
+            "3f2fe1afe015b170808787878787878787878787878787878787878787878787878787878787878787ddcae88c333f8fbf2c1e26571669f2ce41d231ae4ae0abed9847fa500d92e307",
+            "3f2ff98effe833387a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8ab37a69114acb6890e296bfd07c9a9633f0237231b6a990d0c58f770b3d743f5e",
+            "3f300713a273a7a13d518080808080808080808080808080808080808080808080808080808080808080c111b5c0432d505f1eeffc7bc8e38235f0ea0146d7ac506ed11bbe2e8dd844e3",
+            "3f30084dfedc46142ec384848484848484848484848484848484848484848484848484848484848484843cd3d3e4dfe1d2cd76e81146e2d9d64f4b6c3cd953827031ede20f6a7a7c4a58",
+            "3f300abbc55121165eb283838383838383838383838383838383838383838383838383838383838383833ffb5a0dbe680941d33afbd7e73c782e3591f21485fcaee31581fbc68f26a725",
+            "3f300b1385017eff3d089090909090909090909090909090909090909090909090909090909090909090aa9c9b498ede5b1e11cc48ea17fb0e6a8c86e92070c0590bb469a2f7b7579479",
+            "3f300c0dea4c23ae78d58181818181818181818181818181818181818181818181818181818181818181bfed46301bf63819ba4dae77ae6077d7a9b3950210f30768acd2d110797f4adb",
+            "3f300cd70c27290b6c9d92929292929292929292929292929292929292929292929292929292929292923111c2e32bda05f5645cef5f842e4ca17089bf652bc81dbad5e222dc3adf924c",
+            "7f1803021ab2e953706e6d6273803100000000000000320000000000000033000000000000003400000000000000808c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c8c",
+            "7f180ad3d0bc6b59a66e6d62738039000000000000003a000000000000003b000000000000003c00000000000000808e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e8e",
+            "7f19205678077527786e6d6273800500000000000000060000000000000007000000000000000800000000000000808181818181818181818181818181818181818181818181818181818181818181",
+            "7f192c959a943fe8ed6e6d6273802d000000000000002e000000000000002f000000000000003000000000000000808b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b8b",
+            "7f1952d0af9e064d996e6d6273803500000000000000360000000000000037000000000000003800000000000000808d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d8d",
+            "7f1956f7f3af2088eb6e6d62738019000000000000001a000000000000001b000000000000001c00000000000000808686868686868686868686868686868686868686868686868686868686868686",
+            "7f1973442e8600f6b16e6d6273801100000000000000120000000000000013000000000000001400000000000000808484848484848484848484848484848484848484848484848484848484848484",
+            "7f198611a95b8c02246e6d6273800100000000000000020000000000000003000000000000000400000000000000808080808080808080808080808080808080808080808080808080808080808080",
+            "7f198db58363e33a286e6d6273803d000000000000003e000000000000003f000000000000004000000000000000808f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f8f",
+            "7f19c9d7101466db316e6d62738009000000000000000a000000000000000b000000000000000c00000000000000808282828282828282828282828282828282828282828282828282828282828282",
+            "7f19cb36c2b4c450126e6d6273802500000000000000260000000000000027000000000000002800000000000000808989898989898989898989898989898989898989898989898989898989898989",
+            "7f19d519599e6a857f6e6d62738029000000000000002a000000000000002b000000000000002c00000000000000808a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a8a",
+            "7f19dd93e673fca1286e6d62738049000000000000004a000000000000004b000000000000004c00000000000000809292929292929292929292929292929292929292929292929292929292929292",
+            "7f19f51ddc2bdbe5a36e6d6273802100000000000000220000000000000023000000000000002400000000000000808888888888888888888888888888888888888888888888888888888888888888",
+            "7f19f8daf856b19f296e6d6273804500000000000000460000000000000047000000000000004800000000000000809191919191919191919191919191919191919191919191919191919191919191",
+            "7f19ff40b738caccd86e6d6273801500000000000000160000000000000017000000000000001800000000000000808585858585858585858585858585858585858585858585858585858585858585",
+            "7f1a011682f4861a273a6e6d6273804d000000000000004e000000000000004f000000000000005000000000000000809393939393939393939393939393939393939393939393939393939393939393",
+            "7f1a0131180d88ffcf586e6d6273800d000000000000000e000000000000000f000000000000001000000000000000808383838383838383838383838383838383838383838383838383838383838383",
+            "7f1a02f8a1d30bd7eda56e6d6273804100000000000000420000000000000043000000000000004400000000000000809090909090909090909090909090909090909090909090909090909090909090",
+            "7f1a0b878b619aaba2056e6d6273801d000000000000001e000000000000001f000000000000002000000000000000808787878787878787878787878787878787878787878787878787878787878787",
+            "80000380e970c702c194536de1e3834d7c95e0a66a037332b63bf0403258f21058677dbd80498276877a221e06a042712c6ab3ebb10b6b8932af757b149055747dbbc259bf",
+            "80002880fde54d38ca8954783f1711832faa101225dd7f2aa5985dc0c99b67f6a30d488b80852410bbf70d7b2a4f83a3d9bf6fda6c6c288e3e7c6af21a1e5f1bdf55434df7",
+            "80008480ae4a5eb56cd1e5e2891428982f63b6d660c231dcf4ef5ea6d57222c626373434804595dbdcb2f30fd1627c6df78fe320d2092e7994315dad0681cc941a96f88e61",
+            "8000a0806f71c3a93cde231fd1408f164fed04a681930a21ea59b3ee3e54c7b3150621b280b379e4905d70578412650ca4cd4f9ddf6faf5ffb5f0503fe9687e9334b4641be",
+            "8002108032fa2ff074158518071d94f6155836f3efeeeb9e36aac4432eda0dbda69a7f26809b9bdd91b8762ab911fcefe3d2b2c3aceca1fd08c93e11bcbae82654b41b6e61",
+            "800300804b701cd8c852cc9c19134d5ea33a390d88065ef3b4bc911ceb99b01003f8c956806e74066173f779b5a53f41e624e11c07a97fc326969269dfbef6fa705eb26cfa",
+            "80040180a4c74d45f24148d25beccf913f84b0472ec2af425f7f650e4d8af51a7d5db1fc809f26f601edc1cb777f8b15c834247c6739473359bc0cdca8b2189ae76e1b75bc",
+            "80085080b6be9b4a1de078dc76ac7a9a3fec529fdc3db746d5419dcd492dcfcb4a3fe34e80d51c471010f754404e8e32004c93db7baafdf6220b7d0a484ef0ad387e8ce8dd80484c1be01dc1d4c9755b2616ce23b63460e9b63a3ae02234d73680b9784e5063",
+            "800a04804565f8f0a3ba254e258ee3e02b642965952fb4d0d98db66c9885c871e3032ee680065cc13f7cef011119c0a58b0d187c62518b1cdd06e47b1a49e0641a1323d4b48029d568193b28cdefc73591bc91926007a0f6b38e7d2874cfb050747d68065330",
+            "80110080bee317ea15b32becd7e11bcf73080ced4ad31724022f8098e641fe1818895bb380826b288685269d4664623f97f4b2561635d14648bff437c021c745279462d844",
+            "80200680a350294c271d026d5303cbf76ec56a4ed9ae20daf0a4ea53101626fbed19dcae802486e50c786c36d27abbb95fd856b13a5f2e3bb3e4199e0af36c40c026f1166e80a4c0de7fbc0493a22b4928cf90ef10337aa4224d5c0aad5a9fe9cc87cb9e30d4",
+            "8040028051f9f69e801d4e0e1a9bb23701dcf1b80e9746557651039d96844cbf7991396b80d8f9357576ccb1d8b4e346906a6768195f6af383205e138ea351e4d291df0a18",
+            "8080908049a9cf4df4a1b891b270e4e2e1b9c7e71699642a52d2a361e1ac10125a5865048036bf105fa0b4f8728255e93c193604ac0873b28de1850882b831f677ff58a799800999b99221e9ae8a893b4a4dd31c31897e6b8878cb74e5609310f848bae5a878",
+            "809000802e818e994412fd5206e82abe22c075b8e369c99986910fa789f11d2d5db4af0c803d1c4fdd7df7fca3da058d7f00183c1b46a3969798fc7ee0d1b6fb8e8ea5309a",
+            "809001804d32445be9974460c4f89c0cae2ccdd086c04c9a4ca7d13da433cd56e07787ae80fb36ff17553f069869a4c03ddbd3f8dfbeaf46993566d502c08dc384ced5dfa3807b74a17d7f276deba81443554ccffc8b8095f20291ae8e2726a98410ce56a973",
+            "8102c000805b72c32227e3f9bab18391b745e2473898963e88ead1d072177a913d00e5c3a2545d063800a36a99fdfc7c10f6415f6ee610e8030000",
+            "9d0380404683fc89e8233450c8aa1950e75e80f1dbfb2b6d1285f954aae600ddf12a2cacb710138cee60622d0b822ed01acdfe80a4e5476439e80b416d7445ef5e9a8d88e437f2c5a9117c40b3fda0189198008780449fcef31328cceb40ca303ff42412d84e13d009e9f8956dbac69b669e1e866e807d5e64ea8403981992ef203d545c5a49e6ca89dcd43b2d76ecedc36e3c72744e8060fbe617e39d6f7a545855b9fe44671ff2464ca8dbad319540ee8f4f6ad1c1f4808092f5e96c276dba4d67658ab3fee224db9a30ea43bed38b7fa6ff11c344b51380ea1928bc9056cad8c3463c55172d8ecb3f9d9ecba35ad1791dece794116dca3880d43a97d45dc0b0af2017983c02c7ad8c186f126b9a1fb685b7050c8d118321738054a5e74b9db3f026a267c4f62b29f5ab6bca076084b825839fa2b099c60358c3800e6065af91dcbc07b174c9e001f972ca7f17c13c8b21cb35bcb8c3dad4bbc030804b4311fe39b3534486c164d242da59c6b78583bfc12ae64f262e43a0d2ce5cd6",
+            "9f0c014e6bf8b8c2c011e7290b85696bb3f3ba8047e1ca80040e3e00f046b3914158a057428e54bdc4f817da9d05f03dc74c5d8880033474e56f37ef31ff035edf8c498504b8857199e593bfb64092617a53c3929480ade9f17269d1d0cabb2e1eca57bea38817d32eca1e0ea06d346ef5a4206635d68088e7fac52de943478677ad0b6b239f20efa12a2ba34077b5cd41c6ad0bb2f0b38083a1c4edb4c200fde7dad4e79f0c98fb0fccb3f9196d74817319ac4b6668796d80143e5812551e8a50a13fa9000f7c6743634d84f8d4bd36b28e52f862c8ecdc148078b0ae1e0f463376c24590d14cb400d91ed6e1f7e882e5a342d8e5a5b6a9ed21800d1677a2fc095ef1f0e99e9e9e1056abc53ae23696d42694779bf9a8dba1b10c801e8088f6d9e122622bad5bd31cd90fa43f51d492a095f47253d2bc4ba5d8b0b180744933aca8cfd39f393293fd157e19db313d8252e9694c2f0e9db3ad04199169805e95ff00feb14e93b616c3b996fb353403e5960b41a132ae896d5d3e19956a48",
+            "9f0ec5070d609dd3497f72bde07fc96ba0900080df3db3a2acdb1eee522aedd9fe406d208cc3f8b4b811bf7f52a2eec2a153040c80629c11da1820ef8d59252d975f7916c298da1e5746b4fc6169e531637fb8e5b6",
+            "9f0fba98689ebed1138735e0e7a5a790ab60004c5f01d47f736dfc5807b7a3033e859a8dfd04005c5f0339d4183899cf4f5efccdad995b795c1404c8000000",
+            "bf000be78423c7e3ed25234f80d54547285a170f16afec7d161bc6acec3964492a0c000c80b8b787b31282f26f660884bbba97e0188f65a5ead6e21ab4c116bc50f273a9b180a406297a60556bf1968529aa33895a666976ec889710de7d7aa99d9cd1bb5c6e",
         ])),
 ];
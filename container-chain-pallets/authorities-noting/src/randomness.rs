@@ -0,0 +1,61 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Randomness source backed by the relay chain epoch randomness proved by the inherent
+
+use {
+    crate::{Config, RelayEpochRandomness},
+    core::marker::PhantomData,
+    frame_support::traits::Randomness,
+    frame_system::pallet_prelude::BlockNumberFor,
+    parity_scale_codec::{Decode, Encode, MaxEncodedLen},
+    scale_info::TypeInfo,
+    sp_runtime::{traits::Hash, RuntimeDebug},
+};
+
+/// Randomness of a relay chain epoch, as proved by the inherent
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct NotedRandomness<BlockNumber> {
+    /// Index of the relay chain epoch
+    pub epoch_index: u64,
+    /// Randomness of the relay chain epoch
+    pub randomness: [u8; 32],
+    /// First block of this chain in which the randomness was noted
+    pub noted_at: BlockNumber,
+}
+
+/// Implements [`Randomness`] with the last noted relay chain epoch randomness. The output is the
+/// hash of the subject and the randomness, so it only changes once per relay epoch, and the
+/// block number is the first block in which that randomness was noted.
+///
+/// The randomness of a relay epoch is known since the beginning of that epoch, so it must not be
+/// used where knowing it in advance is an issue. Before any randomness is noted, the output only
+/// depends on the subject.
+pub struct RelayRandomness<T>(PhantomData<T>);
+
+impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for RelayRandomness<T> {
+    fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+        let (randomness, noted_at) = RelayEpochRandomness::<T>::get()
+            .map(|noted| (noted.randomness, noted.noted_at))
+            .unwrap_or_default();
+
+        let mut subject = subject.to_vec();
+        subject.reserve(randomness.len());
+        subject.extend_from_slice(&randomness);
+
+        (T::Hashing::hash(&subject[..]), noted_at)
+    }
+}
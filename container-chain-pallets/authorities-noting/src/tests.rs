@@ -15,15 +15,35 @@
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>.
 
 use {
-    crate::{mock::*, Authorities, Event, OrchestratorParaId, ParaId},
+    crate::{
+        migrations::{self, MigrateToBoundedAuthorities},
+        mock::*,
+        AuthorSelectionStrategy, Authorities, AuthoritiesHistorySessions, AuthoritySessionKeys,
+        CanAuthor, ChainMode, Config, DidSetOrchestratorAuthorityData, Error, Event, HaltReason,
+        Halted, LastNotedOrigin, NotHalted, NotedRandomness, OnAuthoritiesChanged,
+        OrchestratorChainMode, OrchestratorParaId, ParaId, ProofFailurePolicy, RandomnessSeeded,
+        RelayRandomness, RelaySlot, RoundRobin, SessionOffsetRoundRobin,
+    },
+    ccp_authorities_noting_runtime_api::BlockProductionStats,
+    dp_collator_assignment::AssignedCollators,
+    dp_core::ParaInfo,
     frame_support::{
-        assert_ok,
+        assert_noop, assert_ok,
         dispatch::GetDispatchInfo,
         inherent::{InherentData, ProvideInherent},
-        traits::UnfilteredDispatchable,
+        traits::{
+            Contains, Everything, GetStorageVersion, OnRuntimeUpgrade, Randomness, StorageVersion,
+            UnfilteredDispatchable,
+        },
+        BoundedVec,
     },
     frame_system::RawOrigin,
-    sp_runtime::traits::BlakeTwo256,
+    parity_scale_codec::Encode,
+    sp_core::H256,
+    sp_runtime::{
+        traits::{BlakeTwo256, Dispatchable, Hash as HashT, Header as HeaderT},
+        BuildStorage,
+    },
     test_relay_sproof_builder::{
         AuthorityAssignmentSproofBuilder, HeaderAs, ParaHeaderSproofBuilder,
         ParaHeaderSproofBuilderItem,
@@ -46,6 +66,38 @@ fn genesis_config_orchestrator_para_id_storage_update() {
     });
 }
 
+#[test]
+fn genesis_config_authorities() {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test> {
+        authorities: vec![10, 11],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::from(t).execute_with(|| {
+        assert_eq!(AuthoritiesNoting::authorities(), vec![10, 11]);
+        assert_eq!(AuthoritiesNoting::expected_author(1), Some(11));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Genesis authorities must not be more than MaxAuthorities")]
+fn genesis_config_too_many_authorities() {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test> {
+        authorities: (0..6).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+}
+
 #[test]
 fn test_authorities_insertion_right_para_id() {
     let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
@@ -134,232 +186,2361 @@ fn test_authorities_insertion_right_para_id_solochain() {
 }
 
 #[test]
-#[should_panic(
-    expected = "Orchestrator chain authorities data needs to be present in every block!"
-)]
-fn test_not_inserting_inherent() {
+fn test_registrar_para_info_insertion_solochain() {
+    let para_info = ParaInfo {
+        manager: 7u64,
+        deposit: 100u128,
+        locked: Some(false),
+    };
+
     let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
     assignment
         .authority_assignment
         .container_chains
         .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::registrar_para_info(ParachainId::get()),
+        para_info.encode(),
+    ));
 
     let (orchestrator_chain_root, orchestrator_chain_state) =
-        assignment.into_state_root_and_proof();
+        assignment.into_state_root_and_proof_solochain();
 
     BlockTests::new()
-        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
-            1 => {
-                let mut s = ParaHeaderSproofBuilderItem::default();
-                s.para_id = OrchestratorParachainId::get();
-                s.author_id =
-                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
-                        parent_hash: Default::default(),
-                        number: Default::default(),
-                        state_root: orchestrator_chain_root,
-                        extrinsics_root: Default::default(),
-                        digest: sp_runtime::generic::Digest { logs: vec![] },
-                    });
-                sproof.items.push(s);
-            }
-            _ => unreachable!(),
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, move || {
+            assert_eq!(
+                AuthoritiesNoting::registrar_para_info(),
+                Some(para_info.clone())
+            );
+            System::assert_has_event(
+                Event::RegistrarParaInfoUpdated {
+                    para_info: Some(para_info.clone()),
+                }
+                .into(),
+            );
         })
-        .with_orchestrator_storage_proof(orchestrator_chain_state)
-        .skip_inherent_insertion()
-        .add(1, || {
-            assert!(AuthoritiesNoting::authorities().is_empty());
+        .add(2, || {
+            // Unchanged, so no event
+            assert!(AuthoritiesNoting::registrar_para_info().is_some());
+            assert!(!System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::AuthoritiesNoting(Event::RegistrarParaInfoUpdated { .. })
+            )));
         });
 }
 
 #[test]
-#[ignore = "used to generate benchmark data"]
-fn encode_proof_for_benchmarks() {
-    println!("pub const ENCODED_PROOFS: &[(u32, (&str, &[&str]), (&str, &[&str]))] = &[");
-
-    for x in 0u32..=0 {
-        let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
-        let mut sproof_builder = test_relay_sproof_builder::ParaHeaderSproofBuilder::default();
-        let container_chain_para_id = 200.into();
-        let orchestrator_para_id = 1000.into();
+fn test_registrar_para_info_insertion_parachain() {
+    let para_info = ParaInfo {
+        manager: 7u64,
+        deposit: 100u128,
+        locked: Some(false),
+    };
 
-        assignment
-            .authority_assignment
-            .container_chains
-            .insert(container_chain_para_id, vec![10u64, 11u64]);
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    // The orchestrator registrar is not the one this chain is registered in
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::registrar_para_info(ParachainId::get()),
+        ParaInfo {
+            manager: 8u64,
+            deposit: 0u128,
+            locked: None,
+        }
+        .encode(),
+    ));
 
-        assignment.session_index = 0; // TODO
-        let (root_b, proof_b) = assignment.into_state_root_and_proof();
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
 
-        let mut s = ParaHeaderSproofBuilderItem::default();
-        s.para_id = orchestrator_para_id;
-        // TODO: this header can be arbitrarily large, because "digest.logs" is an unbounded vec
-        let header = HeaderAs::NonEncoded(dp_core::Header {
-            parent_hash: Default::default(),
-            number: Default::default(),
-            state_root: root_b,
-            extrinsics_root: Default::default(),
-            digest: sp_runtime::generic::Digest { logs: vec![] },
+    let relay_para_info = para_info.clone();
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, _, sproof| {
+            let mut s = ParaHeaderSproofBuilderItem::default();
+            s.para_id = OrchestratorParachainId::get();
+            s.author_id = HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                parent_hash: Default::default(),
+                number: Default::default(),
+                state_root: orchestrator_chain_root,
+                extrinsics_root: Default::default(),
+                digest: sp_runtime::generic::Digest { logs: vec![] },
+            });
+            sproof.items.push(s);
+            sproof.extra_storage.push((
+                dp_core::well_known_keys::registrar_para_info(ParachainId::get()),
+                relay_para_info.encode(),
+            ));
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, move || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(
+                AuthoritiesNoting::registrar_para_info(),
+                Some(para_info.clone())
+            );
         });
-        s.author_id = header;
-        sproof_builder.items.push(s);
+}
 
-        let (root_a, proof_a) = sproof_builder.into_state_root_and_proof();
+#[test]
+fn test_registrar_para_info_not_in_proof() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
 
-        println!("({}, (\"{}\", &[", x, hex::encode(root_a),);
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
 
-        for x in proof_a.iter_nodes() {
-            println!("\"{}\",", hex::encode(x));
-        }
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::registrar_para_info(), None);
+        });
+}
 
-        println!("]), (");
+#[test]
+fn test_chain_halted_when_paused() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::SOLOCHAIN_REGISTERED_PARA_IDS.to_vec(),
+        Vec::<ParaId>::new().encode(),
+    ));
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::SOLOCHAIN_PAUSED_PARA_IDS.to_vec(),
+        vec![ParachainId::get()].encode(),
+    ));
 
-        println!("\"{}\", &[", hex::encode(root_b),);
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
 
-        for x in proof_b.iter_nodes() {
-            println!("\"{}\",", hex::encode(x));
-        }
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            // Paused chains have no authorities assigned
+            assert_eq!(AuthoritiesNoting::authorities(), vec![]);
+            assert_eq!(AuthoritiesNoting::halted(), Some(HaltReason::Paused));
+            System::assert_has_event(
+                Event::ChainHalted {
+                    reason: HaltReason::Paused,
+                }
+                .into(),
+            );
 
-        println!("])),");
-    }
+            // The other inherents of the block are still applied
+            let set_timestamp = RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 1 });
+            assert_ok!(set_timestamp.dispatch(RuntimeOrigin::none()));
 
-    println!("];")
+            // But not the extrinsics after them
+            System::note_inherents_applied();
+            let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+            assert_noop!(
+                remark.clone().dispatch(RuntimeOrigin::signed(1)),
+                frame_system::Error::<Test>::CallFiltered
+            );
+            assert!(NotHalted::<Test, Everything>::contains(&remark));
+        })
+        .add(2, || {
+            // Still halted, so no event
+            assert!(AuthoritiesNoting::is_halted());
+            assert!(!System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::AuthoritiesNoting(Event::ChainHalted { .. })
+            )));
+        });
 }
 
 #[test]
-fn test_set_authorities() {
+fn test_chain_resumed_when_registered() {
     let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
     assignment
         .authority_assignment
         .container_chains
         .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::SOLOCHAIN_REGISTERED_PARA_IDS.to_vec(),
+        vec![ParachainId::get()].encode(),
+    ));
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::SOLOCHAIN_PAUSED_PARA_IDS.to_vec(),
+        Vec::<ParaId>::new().encode(),
+    ));
 
     let (orchestrator_chain_root, orchestrator_chain_state) =
-        assignment.into_state_root_and_proof();
+        assignment.into_state_root_and_proof_solochain();
 
     BlockTests::new()
-        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
-            1 => {
-                let mut s = ParaHeaderSproofBuilderItem::default();
-                s.para_id = OrchestratorParachainId::get();
-                s.author_id =
-                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
-                        parent_hash: Default::default(),
-                        number: Default::default(),
-                        state_root: orchestrator_chain_root,
-                        extrinsics_root: Default::default(),
-                        digest: sp_runtime::generic::Digest { logs: vec![] },
-                    });
-                sproof.items.push(s);
-            }
-            _ => unreachable!(),
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert!(!AuthoritiesNoting::is_halted());
+            Halted::<Test>::put(HaltReason::NotRegistered);
         })
-        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(2, || {
+            assert!(!AuthoritiesNoting::is_halted());
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            System::assert_has_event(Event::ChainResumed.into());
+        });
+}
+
+#[test]
+fn test_chain_not_halted_without_paused_para_ids() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::SOLOCHAIN_REGISTERED_PARA_IDS.to_vec(),
+        vec![ParaId::from(2001)].encode(),
+    ));
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
         .add(1, || {
-            let authorities = vec![4, 5];
-            assert_ok!(AuthoritiesNoting::set_authorities(
-                RuntimeOrigin::root(),
-                authorities.clone()
-            ));
-            assert_eq!(Authorities::<Test>::get(), authorities);
-            System::assert_last_event(Event::AuthoritiesInserted { authorities }.into());
+            // The paused para ids are not in the proof, so the status is unknown
+            assert!(!AuthoritiesNoting::is_halted());
         });
 }
 
 #[test]
-fn test_set_orchestrator_para_id() {
+fn test_relay_randomness_insertion() {
     let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
     assignment
         .authority_assignment
         .container_chains
         .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::RELAY_EPOCH_INDEX.to_vec(),
+        5u64.encode(),
+    ));
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::RELAY_EPOCH_RANDOMNESS.to_vec(),
+        [7u8; 32].encode(),
+    ));
 
     let (orchestrator_chain_root, orchestrator_chain_state) =
-        assignment.into_state_root_and_proof();
+        assignment.into_state_root_and_proof_solochain();
 
     BlockTests::new()
-        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
-            1 => {
-                let mut s = ParaHeaderSproofBuilderItem::default();
-                s.para_id = OrchestratorParachainId::get();
-                s.author_id =
-                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
-                        parent_hash: Default::default(),
-                        number: Default::default(),
-                        state_root: orchestrator_chain_root,
-                        extrinsics_root: Default::default(),
-                        digest: sp_runtime::generic::Digest { logs: vec![] },
-                    });
-                sproof.items.push(s);
-            }
-            _ => unreachable!(),
-        })
-        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
         .add(1, || {
-            let new_para_id = ParaId::new(2000);
-            assert_ok!(AuthoritiesNoting::set_orchestrator_para_id(
-                RuntimeOrigin::root(),
-                new_para_id
-            ));
-            assert_eq!(OrchestratorParaId::<Test>::get(), new_para_id);
-            System::assert_last_event(
-                Event::OrchestratorParachainIdUpdated {
-                    new_para_id: 2000.into(),
-                }
-                .into(),
+            assert_eq!(
+                AuthoritiesNoting::relay_epoch_randomness(),
+                Some(NotedRandomness {
+                    epoch_index: 5,
+                    randomness: [7u8; 32],
+                    noted_at: 1,
+                })
+            );
+            System::assert_has_event(Event::RelayRandomnessUpdated { epoch_index: 5 }.into());
+
+            let subject = b"subject".to_vec();
+            assert_eq!(
+                RelayRandomness::<Test>::random(&subject),
+                (BlakeTwo256::hash(&[subject, vec![7u8; 32]].concat()), 1)
             );
+        })
+        .add(2, || {
+            // Same epoch, so no event and the randomness is still the one noted in block 1
+            assert_eq!(RelayRandomness::<Test>::random(b"subject").1, 1);
+            assert!(!System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::AuthoritiesNoting(Event::RelayRandomnessUpdated { .. })
+            )));
         });
 }
 
 #[test]
-fn weights_assigned_to_extrinsics_are_correct() {
-    new_test_ext().execute_with(|| {
-        assert_eq!(
-            crate::Call::<Test>::set_authorities {
-                authorities: vec![]
-            }
-            .get_dispatch_info()
-            .call_weight,
-            <() as crate::weights::WeightInfo>::set_authorities(0u32)
-        );
+fn test_relay_randomness_not_in_proof() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
 
-        assert_eq!(
-            crate::Call::<Test>::set_orchestrator_para_id {
-                new_para_id: 1u32.into()
-            }
-            .get_dispatch_info()
-            .call_weight,
-            <() as crate::weights::WeightInfo>::set_orchestrator_para_id()
-        );
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
 
-        let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
-        assignment
-            .authority_assignment
-            .container_chains
-            .insert(ParachainId::get(), vec![10u64, 11u64]);
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::relay_epoch_randomness(), None);
+            assert_eq!(
+                RelayRandomness::<Test>::random(b"subject"),
+                (
+                    BlakeTwo256::hash(&[&b"subject"[..], &[0u8; 32][..]].concat()),
+                    0
+                )
+            );
+        });
+}
 
-        let (orchestrator_chain_root, orchestrator_chain_state) =
-            assignment.into_state_root_and_proof();
+fn relay_slot_proof(relay_slot: u64) -> (H256, sp_trie::StorageProof) {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::RELAY_CURRENT_SLOT.to_vec(),
+        relay_slot.encode(),
+    ));
 
-        let mut sproof_builder = ParaHeaderSproofBuilder::default();
+    assignment.into_state_root_and_proof_solochain()
+}
 
-        let mut s = ParaHeaderSproofBuilderItem::default();
-        s.para_id = OrchestratorParachainId::get();
-        s.author_id = HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
-            parent_hash: Default::default(),
-            number: Default::default(),
-            state_root: orchestrator_chain_root,
-            extrinsics_root: Default::default(),
-            digest: sp_runtime::generic::Digest { logs: vec![] },
-        });
-        sproof_builder.items.push(s);
+#[test]
+fn test_relay_time_drift_within_tolerance() {
+    MaxRelayTimeDrift::set(Some(12_000));
+    // 2 relay slots after the start of slot 100
+    MockNow::set(100 * 6000 + 12_000);
 
-        let (relay_root, relay_chain_state) = sproof_builder.into_state_root_and_proof();
-        frame_support::storage::unhashed::put(MOCK_RELAY_ROOT_KEY, &relay_root);
+    let (relay_root, relay_proof) = relay_slot_proof(100);
 
-        let mut inherent_data = InherentData::default();
-        let system_inherent_data =
-            ccp_authorities_noting_inherent::ContainerChainAuthoritiesInherentData {
-                relay_chain_state,
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_proof)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(RelaySlot::<Test>::get(), Some(100));
+        })
+        .add(2, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        });
+}
+
+#[test]
+#[should_panic(expected = "drifts more than 12000ms from relay slot 100")]
+fn test_relay_time_drift_too_large() {
+    MaxRelayTimeDrift::set(Some(12_000));
+    MockNow::set(100 * 6000 - 12_001);
+
+    let (relay_root, relay_proof) = relay_slot_proof(100);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_proof)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {});
+}
+
+#[test]
+#[should_panic(expected = "Relay slot must be proved while the time drift is checked")]
+fn test_relay_time_drift_slot_not_in_proof() {
+    MaxRelayTimeDrift::set(Some(12_000));
+
+    let (relay_root, relay_proof) = solochain_assignment_proof(0);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_proof)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(RelaySlot::<Test>::get(), None);
+        });
+}
+
+#[test]
+fn test_relay_time_drift_not_checked_if_relay_proof_rejected() {
+    MaxRelayTimeDrift::set(Some(12_000));
+    MalformedProofPolicy::set(ProofFailurePolicy::KeepPrevious);
+    MockNow::set(0);
+
+    let (_, relay_proof) = relay_slot_proof(100);
+
+    BlockTests::new()
+        .with_relay_storage_proof(H256::repeat_byte(1), relay_proof)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(RelaySlot::<Test>::get(), None);
+            System::assert_last_event(
+                Event::AuthoritiesNotingFailed {
+                    error: Error::<Test>::InvalidRelayChainStateProof.into(),
+                }
+                .into(),
+            );
+        })
+        .add(2, || {
+            assert_eq!(RelaySlot::<Test>::get(), None);
+        });
+}
+
+#[test]
+fn test_relay_time_drift_check_disabled() {
+    MaxRelayTimeDrift::set(None);
+    MockNow::set(0);
+
+    let (relay_root, relay_proof) = relay_slot_proof(100);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_proof)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        });
+}
+
+/// Proof of authorities 10 and 11, with the session keys of authority 10 only
+fn session_keys_proof() -> (H256, sp_trie::StorageProof) {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::session_key_owner(
+            nimbus_primitives::NIMBUS_KEY_ID,
+            &10u64.encode(),
+        ),
+        [7u8; 32].encode(),
+    ));
+    assignment.extra_storage.push((
+        dp_core::well_known_keys::session_next_keys(&[7u8; 32].encode()),
+        1234u128.encode(),
+    ));
+
+    assignment.into_state_root_and_proof_solochain()
+}
+
+#[test]
+fn test_authority_session_keys_insertion() {
+    let (orchestrator_chain_root, orchestrator_chain_state) = session_keys_proof();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authority_session_keys(10u64), Some(1234));
+            assert_eq!(AuthoritiesNoting::authority_session_keys(11u64), None);
+        });
+}
+
+#[test]
+fn test_authority_session_keys_noting_disabled() {
+    NoteAuthoritySessionKeys::set(false);
+    let (orchestrator_chain_root, orchestrator_chain_state) = session_keys_proof();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::authority_session_keys(10u64), None);
+        });
+}
+
+#[test]
+fn test_authority_session_keys_removed_with_authority() {
+    new_test_ext().execute_with(|| {
+        Authorities::<Test>::put(BoundedVec::truncate_from(vec![10u64, 11u64]));
+        AuthoritySessionKeys::<Test>::insert(10u64, 1234);
+        AuthoritySessionKeys::<Test>::insert(11u64, 5678);
+
+        AuthoritiesNoting::note_authority_session_keys(
+            &BoundedVec::truncate_from(vec![11u64]),
+            vec![(11u64, 9999)],
+        );
+
+        assert_eq!(AuthoritiesNoting::authority_session_keys(10u64), None);
+        assert_eq!(AuthoritiesNoting::authority_session_keys(11u64), Some(9999));
+    });
+}
+
+#[test]
+fn test_next_authorities_insertion() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    let mut next_assignment = AssignedCollators::default();
+    next_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![12u64, 13u64]);
+    assignment.next_authority_assignment = Some(next_assignment);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
+            1 => {
+                let mut s = ParaHeaderSproofBuilderItem::default();
+                s.para_id = OrchestratorParachainId::get();
+                s.author_id =
+                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                        parent_hash: Default::default(),
+                        number: Default::default(),
+                        state_root: orchestrator_chain_root,
+                        extrinsics_root: Default::default(),
+                        digest: sp_runtime::generic::Digest { logs: vec![] },
+                    });
+                sproof.items.push(s);
+            }
+            _ => unreachable!(),
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::next_authorities(), vec![12u64, 13u64]);
+        });
+}
+
+#[test]
+fn test_orchestrator_authorities_insertion() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment.authority_assignment.orchestrator_chain = vec![1u64, 2u64, 3u64, 4u64];
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
+            1 => {
+                let mut s = ParaHeaderSproofBuilderItem::default();
+                s.para_id = OrchestratorParachainId::get();
+                s.author_id =
+                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                        parent_hash: Default::default(),
+                        number: Default::default(),
+                        state_root: orchestrator_chain_root,
+                        extrinsics_root: Default::default(),
+                        digest: sp_runtime::generic::Digest { logs: vec![] },
+                    });
+                sproof.items.push(s);
+            }
+            _ => unreachable!(),
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            // Truncated to MaxOrchestratorAuthorities
+            assert_eq!(
+                AuthoritiesNoting::orchestrator_authorities(),
+                vec![1u64, 2u64, 3u64]
+            );
+
+            // The collators of the previous orchestrator are forgotten
+            assert_ok!(AuthoritiesNoting::set_orchestrator_para_id(
+                RuntimeOrigin::root(),
+                ParaId::new(2000)
+            ));
+            assert!(AuthoritiesNoting::orchestrator_authorities().is_empty());
+        });
+}
+
+#[test]
+fn test_next_authorities_not_in_proof() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
+            1 => {
+                let mut s = ParaHeaderSproofBuilderItem::default();
+                s.para_id = OrchestratorParachainId::get();
+                s.author_id =
+                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                        parent_hash: Default::default(),
+                        number: Default::default(),
+                        state_root: orchestrator_chain_root,
+                        extrinsics_root: Default::default(),
+                        digest: sp_runtime::generic::Digest { logs: vec![] },
+                    });
+                sproof.items.push(s);
+            }
+            _ => unreachable!(),
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            // Current authorities are still noted even if the next assignment is missing
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert!(AuthoritiesNoting::next_authorities().is_empty());
+        });
+}
+
+#[test]
+fn test_next_authorities_insertion_solochain() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    let mut next_assignment = AssignedCollators::default();
+    next_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![12u64, 13u64]);
+    assignment.next_authority_assignment = Some(next_assignment);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::next_authorities(), vec![12u64, 13u64]);
+        });
+}
+
+#[test]
+fn test_authorities_history_insertion() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 3;
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, _relay_block_num, sproof| {
+            let mut s = ParaHeaderSproofBuilderItem::default();
+            s.para_id = OrchestratorParachainId::get();
+            s.author_id = HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                parent_hash: Default::default(),
+                number: Default::default(),
+                state_root: orchestrator_chain_root,
+                extrinsics_root: Default::default(),
+                digest: sp_runtime::generic::Digest { logs: vec![] },
+            });
+            sproof.items.push(s);
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            assert_eq!(
+                AuthoritiesNoting::authorities_for_session(3),
+                Some(vec![10u64, 11u64])
+            );
+            assert_eq!(
+                AuthoritiesHistorySessions::<Test>::get().to_vec(),
+                vec![(3, 1)]
+            );
+        })
+        .add(2, || {
+            // Same session, the first noted block is kept
+            assert_eq!(
+                AuthoritiesHistorySessions::<Test>::get().to_vec(),
+                vec![(3, 1)]
+            );
+            assert_eq!(
+                AuthoritiesNoting::authorities_at(2),
+                Some(vec![10u64, 11u64])
+            );
+            assert_eq!(AuthoritiesNoting::authorities_at(0), None);
+        });
+}
+
+#[test]
+fn test_authorities_history_is_pruned() {
+    new_test_ext().execute_with(|| {
+        // History depth is 4 in the mock
+        for session in 0u32..6 {
+            System::set_block_number((session * 10 + 1).into());
+            AuthoritiesNoting::note_authorities_history(
+                session,
+                &BoundedVec::truncate_from(vec![session.into()]),
+            );
+        }
+
+        assert_eq!(
+            AuthoritiesHistorySessions::<Test>::get().to_vec(),
+            vec![(2, 21), (3, 31), (4, 41), (5, 51)]
+        );
+        assert_eq!(AuthoritiesNoting::authorities_for_session(1), None);
+        assert_eq!(AuthoritiesNoting::authorities_for_session(2), Some(vec![2]));
+
+        // Blocks before the oldest session in the history are unknown
+        assert_eq!(AuthoritiesNoting::authorities_at(20), None);
+        assert_eq!(AuthoritiesNoting::authorities_at(21), Some(vec![2]));
+        assert_eq!(AuthoritiesNoting::authorities_at(40), Some(vec![3]));
+        assert_eq!(AuthoritiesNoting::authorities_at(100), Some(vec![5]));
+    });
+}
+
+#[test]
+fn test_last_noted_origin() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 5;
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    let orchestrator_header = sp_runtime::generic::Header::<u32, BlakeTwo256> {
+        parent_hash: Default::default(),
+        number: 7,
+        state_root: orchestrator_chain_root,
+        extrinsics_root: Default::default(),
+        digest: sp_runtime::generic::Digest { logs: vec![] },
+    };
+    let orchestrator_header_hash = orchestrator_header.hash();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
+            1 => {
+                let mut s = ParaHeaderSproofBuilderItem::default();
+                s.para_id = OrchestratorParachainId::get();
+                s.author_id = HeaderAs::NonEncoded(orchestrator_header.clone());
+                sproof.items.push(s);
+            }
+            _ => unreachable!(),
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, move || {
+            let origin = AuthoritiesNoting::last_noted_origin().expect("origin to be noted");
+            assert_eq!(origin.orchestrator_session_index, 5);
+            assert_eq!(origin.orchestrator_block_number, Some(7));
+            assert_eq!(
+                origin.orchestrator_block_hash,
+                Some(orchestrator_header_hash)
+            );
+            assert_eq!(
+                origin.relay_parent_storage_root,
+                frame_support::storage::unhashed::get::<H256>(MOCK_RELAY_ROOT_KEY).unwrap()
+            );
+        });
+}
+
+#[test]
+fn test_last_noted_origin_solochain() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 5;
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, move || {
+            let origin = AuthoritiesNoting::last_noted_origin().expect("origin to be noted");
+            assert_eq!(origin.orchestrator_session_index, 5);
+            assert_eq!(origin.orchestrator_block_number, None);
+            assert_eq!(origin.orchestrator_block_hash, None);
+            assert_eq!(origin.relay_parent_storage_root, orchestrator_chain_root);
+        });
+}
+
+#[test]
+fn test_last_noted_origin_not_set_on_failure() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get() + 1, vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert!(AuthoritiesNoting::authorities().is_empty());
+            assert_eq!(AuthoritiesNoting::last_noted_origin(), None);
+        });
+}
+
+#[test]
+fn test_expected_author() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(AuthoritiesNoting::expected_author(0), None);
+        assert!(!<CanAuthor<Test> as nimbus_primitives::CanAuthor<u64>>::can_author(&10, &0));
+
+        Authorities::<Test>::put(BoundedVec::truncate_from(vec![10u64, 11u64, 12u64]));
+
+        assert_eq!(AuthoritiesNoting::expected_author(0), Some(10));
+        assert_eq!(AuthoritiesNoting::expected_author(4), Some(11));
+        assert!(<CanAuthor<Test> as nimbus_primitives::CanAuthor<u64>>::can_author(&12, &5));
+        assert!(!<CanAuthor<Test> as nimbus_primitives::CanAuthor<u64>>::can_author(&10, &5));
+    });
+}
+
+#[test]
+fn test_find_nimbus_author() {
+    use {
+        crate::FindNimbusAuthor,
+        frame_support::traits::FindAuthor,
+        nimbus_primitives::NIMBUS_ENGINE_ID,
+        sp_consensus_aura::{Slot, AURA_ENGINE_ID},
+    };
+
+    new_test_ext().execute_with(|| {
+        Authorities::<Test>::put(BoundedVec::truncate_from(vec![10u64, 11u64, 12u64]));
+
+        let nimbus = 20u64.encode();
+        let aura = Slot::from(4u64).encode();
+
+        // The nimbus digest takes precedence over the aura slot
+        assert_eq!(
+            FindNimbusAuthor::<Test>::find_author([
+                (AURA_ENGINE_ID, &aura[..]),
+                (NIMBUS_ENGINE_ID, &nimbus[..]),
+            ]),
+            Some(20)
+        );
+        assert_eq!(
+            FindNimbusAuthor::<Test>::find_author([(AURA_ENGINE_ID, &aura[..])]),
+            Some(11)
+        );
+        assert_eq!(
+            FindNimbusAuthor::<Test>::find_author(core::iter::empty()),
+            None
+        );
+
+        Authorities::<Test>::kill();
+        assert_eq!(
+            FindNimbusAuthor::<Test>::find_author([(AURA_ENGINE_ID, &aura[..])]),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_aura_authorities() {
+    use {
+        crate::to_aura_authorities, nimbus_primitives::NimbusId,
+        sp_consensus_aura::sr25519::AuthorityId as AuraId, sp_core::crypto::ByteArray,
+    };
+
+    // The mock authorities are not keys, so the conversion is checked on nimbus keys
+    let authorities: Vec<NimbusId> = [3u8, 1, 2]
+        .iter()
+        .map(|byte| NimbusId::from_slice(&[*byte; 32]).unwrap())
+        .collect();
+    let expected: Vec<AuraId> = [3u8, 1, 2]
+        .iter()
+        .map(|byte| AuraId::from_slice(&[*byte; 32]).unwrap())
+        .collect();
+
+    // The order of the authorities is kept
+    assert_eq!(to_aura_authorities::<_, AuraId>(&authorities), expected);
+    assert_eq!(
+        to_aura_authorities::<NimbusId, AuraId>(&[]),
+        Vec::<AuraId>::new()
+    );
+
+    // Authorities that are not valid aura keys are skipped
+    let ecdsa_authorities = [sp_core::ecdsa::Public::from_raw([1u8; 33])];
+    assert_eq!(
+        to_aura_authorities::<_, AuraId>(&ecdsa_authorities),
+        Vec::<AuraId>::new()
+    );
+}
+
+struct StaticNimbusAuthor;
+
+impl frame_support::traits::FindAuthor<nimbus_primitives::NimbusId> for StaticNimbusAuthor {
+    fn find_author<'a, I>(_digests: I) -> Option<nimbus_primitives::NimbusId>
+    where
+        I: 'a + IntoIterator<Item = (frame_support::ConsensusEngineId, &'a [u8])>,
+    {
+        Some(sp_core::crypto::ByteArray::from_slice(&(0u8..32).collect::<Vec<_>>()).unwrap())
+    }
+}
+
+#[test]
+fn test_find_mapped_author() {
+    use {
+        crate::{FindMappedAuthor, TruncatedH160Lookup},
+        frame_support::traits::FindAuthor,
+        sp_core::H160,
+    };
+
+    assert_eq!(
+        FindMappedAuthor::<StaticNimbusAuthor, TruncatedH160Lookup>::find_author(
+            core::iter::empty()
+        ),
+        Some(H160::from_slice(&(4u8..24).collect::<Vec<_>>()))
+    );
+}
+
+#[test]
+fn test_session_offset_round_robin() {
+    let authorities = [10u64, 11u64, 12u64];
+
+    assert_eq!(
+        <SessionOffsetRoundRobin as AuthorSelectionStrategy<u64>>::select_author(&[], 0, 0),
+        None
+    );
+    assert_eq!(
+        SessionOffsetRoundRobin::select_author(&authorities, 0, 0),
+        Some(10)
+    );
+    assert_eq!(
+        SessionOffsetRoundRobin::select_author(&authorities, 0, 1),
+        Some(11)
+    );
+    assert_eq!(
+        SessionOffsetRoundRobin::select_author(&authorities, 2, 1),
+        Some(10)
+    );
+    assert_eq!(
+        SessionOffsetRoundRobin::select_author(&authorities, u32::MAX, u32::MAX),
+        RoundRobin::select_author(&authorities, 0, 0)
+    );
+    assert_eq!(
+        SessionOffsetRoundRobin::slot_ordering(&authorities, 1),
+        vec![11, 12, 10]
+    );
+}
+
+struct SessionRandomness;
+
+impl frame_support::traits::Randomness<H256, u64> for SessionRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        (BlakeTwo256::hash(subject), 0)
+    }
+}
+
+#[test]
+fn test_randomness_seeded_ordering() {
+    type Strategy = RandomnessSeeded<Test, SessionRandomness>;
+
+    let authorities: Vec<u64> = (0..10).collect();
+
+    assert_eq!(
+        <Strategy as AuthorSelectionStrategy<u64>>::select_author(&[], 0, 0),
+        None
+    );
+
+    let ordering = |session_index| {
+        (0..authorities.len() as u32)
+            .map(|slot| Strategy::select_author(&authorities, slot, session_index).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    // Every authority gets exactly one slot per round
+    let mut sorted = ordering(1);
+    sorted.sort();
+    assert_eq!(sorted, authorities);
+
+    // The ordering is stable within a session, and repeats every round
+    assert_eq!(ordering(1), ordering(1));
+    assert_eq!(
+        Strategy::select_author(&authorities, 3, 1),
+        Strategy::select_author(&authorities, 13, 1)
+    );
+
+    // Different sessions are shuffled differently
+    assert_ne!(ordering(1), ordering(2));
+    // The ordering used to select the authors of several slots at once is the same
+    assert_eq!(Strategy::slot_ordering(&authorities, 1), ordering(1));
+}
+
+#[test]
+fn test_last_noted_session() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 2;
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
+
+    new_test_ext().execute_with(|| {
+        assert_eq!(AuthoritiesNoting::last_noted_session(), None);
+    });
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::last_noted_session(), Some(2));
+        });
+}
+
+/// Notes valid authorities in block 1, and in block 2 provides an orchestrator header whose
+/// state root does not match the orchestrator chain state proof
+fn malformed_orchestrator_proof_in_second_block() -> BlockTests {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| {
+            let state_root = match relay_block_num {
+                1 => orchestrator_chain_root,
+                2 => H256::repeat_byte(1),
+                _ => unreachable!(),
+            };
+            let mut s = ParaHeaderSproofBuilderItem::default();
+            s.para_id = OrchestratorParachainId::get();
+            s.author_id = HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                parent_hash: Default::default(),
+                number: relay_block_num,
+                state_root,
+                extrinsics_root: Default::default(),
+                digest: sp_runtime::generic::Digest { logs: vec![] },
+            });
+            sproof.items.push(s);
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        })
+}
+
+#[test]
+fn test_malformed_proof_keep_previous() {
+    MalformedProofPolicy::set(ProofFailurePolicy::KeepPrevious);
+
+    malformed_orchestrator_proof_in_second_block().add(2, || {
+        assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        System::assert_last_event(
+            Event::AuthoritiesNotingFailed {
+                error: Error::<Test>::InvalidOrchestratorChainStateProof.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_malformed_proof_clear() {
+    MalformedProofPolicy::set(ProofFailurePolicy::Clear);
+
+    malformed_orchestrator_proof_in_second_block().add(2, || {
+        assert!(AuthoritiesNoting::authorities().is_empty());
+        assert!(AuthoritiesNoting::next_authorities().is_empty());
+        System::assert_last_event(
+            Event::AuthoritiesNotingFailed {
+                error: Error::<Test>::InvalidOrchestratorChainStateProof.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Invalid authorities noting proof")]
+fn test_malformed_proof_panic() {
+    MalformedProofPolicy::set(ProofFailurePolicy::Panic);
+
+    malformed_orchestrator_proof_in_second_block().add(2, || {});
+}
+
+#[test]
+fn test_authorities_not_found_emits_event() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get() + 1, vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            System::assert_last_event(
+                Event::AuthoritiesNotingFailed {
+                    error: Error::<Test>::NoAuthoritiesFound.into(),
+                }
+                .into(),
+            );
+        });
+}
+
+/// Notes valid authorities in the blocks listed in `valid_blocks`, and provides an undecodable
+/// orchestrator header in every other block
+fn undecodable_orchestrator_header_except_in(valid_blocks: &'static [u32]) -> BlockTests {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| {
+            let mut s = ParaHeaderSproofBuilderItem::default();
+            s.para_id = OrchestratorParachainId::get();
+            s.author_id = if valid_blocks.contains(&relay_block_num) {
+                HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                    parent_hash: Default::default(),
+                    number: relay_block_num,
+                    state_root: orchestrator_chain_root,
+                    extrinsics_root: Default::default(),
+                    digest: sp_runtime::generic::Digest { logs: vec![] },
+                })
+            } else {
+                HeaderAs::AlreadyEncoded(vec![1, 2, 3])
+            };
+            sproof.items.push(s);
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+}
+
+#[test]
+fn test_grace_period_keeps_authorities_until_expired() {
+    AuthoritiesGracePeriod::set(2);
+
+    undecodable_orchestrator_header_except_in(&[1])
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), None);
+        })
+        .add(2, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), Some(4));
+            System::assert_last_event(Event::GracePeriodStarted { expires_at: 4 }.into());
+        })
+        .add(3, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert!(<CanAuthor<Test> as nimbus_primitives::CanAuthor<u64>>::can_author(&10, &0));
+            System::assert_last_event(
+                Event::AuthoritiesNotingFailed {
+                    error: Error::<Test>::FailedDecodingHeader.into(),
+                }
+                .into(),
+            );
+        })
+        .add(4, || {
+            assert!(AuthoritiesNoting::authorities().is_empty());
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), None);
+            System::assert_has_event(
+                Event::AuthoritiesChanged {
+                    old_session: Some(0),
+                    new_session: 0,
+                    added: vec![],
+                    removed: vec![10u64, 11u64],
+                }
+                .into(),
+            );
+            System::assert_last_event(Event::GracePeriodExpired.into());
+        })
+        .add(5, || {
+            assert!(AuthoritiesNoting::authorities().is_empty());
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), None);
+        });
+}
+
+#[test]
+fn test_grace_period_ends_when_noting_succeeds() {
+    AuthoritiesGracePeriod::set(2);
+
+    undecodable_orchestrator_header_except_in(&[1, 3])
+        .add(1, || {})
+        .add(2, || {
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), Some(4));
+        })
+        .add(3, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), None);
+        })
+        .add(4, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), Some(6));
+        });
+}
+
+#[test]
+fn test_no_grace_period_clears_authorities() {
+    undecodable_orchestrator_header_except_in(&[1])
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        })
+        .add(2, || {
+            assert!(AuthoritiesNoting::authorities().is_empty());
+            assert_eq!(AuthoritiesNoting::grace_period_expires_at(), None);
+            System::assert_last_event(
+                Event::AuthoritiesChanged {
+                    old_session: Some(0),
+                    new_session: 0,
+                    added: vec![],
+                    removed: vec![10u64, 11u64],
+                }
+                .into(),
+            );
+        })
+        .add(3, || {
+            // Nothing left to remove
+            assert!(!System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::AuthoritiesNoting(Event::AuthoritiesChanged { .. })
+            )));
+        });
+}
+
+#[test]
+fn test_clearing_authorities_calls_on_authorities_changed() {
+    for grace_period in [0, 2] {
+        AuthoritiesGracePeriod::set(grace_period);
+        AuthoritiesChangedCalls::set(vec![]);
+
+        // Cleared in block 2 without a grace period, in block 4 once the grace period expired
+        let cleared_in = 2 + grace_period;
+        let mut tests = undecodable_orchestrator_header_except_in(&[1]);
+        for n in 1..=cleared_in + 1 {
+            tests = tests.add(n, move || {
+                let call = (vec![10u64, 11u64], vec![], Some(0));
+                match n {
+                    n if n < cleared_in => assert_eq!(AuthoritiesChangedCalls::get().len(), 2),
+                    n if n == cleared_in => {
+                        assert!(AuthoritiesNoting::authorities().is_empty());
+                        assert_eq!(AuthoritiesChangedCalls::get()[2..], [call.clone(), call]);
+                    }
+                    _ => assert_eq!(AuthoritiesChangedCalls::get().len(), 4),
+                }
+            });
+        }
+        tests.run();
+    }
+}
+
+#[test]
+fn test_authorities_changed_event() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 5;
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(orchestrator_chain_root, orchestrator_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            System::assert_has_event(
+                Event::AuthoritiesChanged {
+                    old_session: None,
+                    new_session: 5,
+                    added: vec![10u64, 11u64],
+                    removed: vec![],
+                }
+                .into(),
+            );
+
+            // Simulate a different set noted in the previous session
+            Authorities::<Test>::put(BoundedVec::truncate_from(vec![10u64, 12u64]));
+        })
+        .add(2, || {
+            System::assert_has_event(
+                Event::AuthoritiesChanged {
+                    old_session: Some(5),
+                    new_session: 5,
+                    added: vec![11u64],
+                    removed: vec![12u64],
+                }
+                .into(),
+            );
+        })
+        .add(3, || {
+            assert!(!System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::AuthoritiesNoting(Event::AuthoritiesChanged { .. })
+            )));
+        });
+}
+
+#[test]
+#[should_panic(
+    expected = "Orchestrator chain authorities data needs to be present in every block!"
+)]
+fn test_not_inserting_inherent() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
+            1 => {
+                let mut s = ParaHeaderSproofBuilderItem::default();
+                s.para_id = OrchestratorParachainId::get();
+                s.author_id =
+                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                        parent_hash: Default::default(),
+                        number: Default::default(),
+                        state_root: orchestrator_chain_root,
+                        extrinsics_root: Default::default(),
+                        digest: sp_runtime::generic::Digest { logs: vec![] },
+                    });
+                sproof.items.push(s);
+            }
+            _ => unreachable!(),
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .skip_inherent_insertion()
+        .add(1, || {
+            assert!(AuthoritiesNoting::authorities().is_empty());
+        });
+}
+
+#[test]
+#[ignore = "used to generate benchmark data"]
+fn encode_proof_for_benchmarks() {
+    // The output replaces everything below the license header of `mock_proof.rs`
+    let session_index = 1000u32;
+    let container_chain_para_id = 200u32;
+    let orchestrator_para_id = 1000u32;
+
+    println!("/// Orchestrator session of the assignments in `ENCODED_PROOFS`");
+    println!("pub const SESSION_INDEX: u32 = {};", session_index);
+    println!("/// Para id of the container chain assigned the authorities in `ENCODED_PROOFS`");
+    println!(
+        "pub const CONTAINER_CHAIN_PARA_ID: u32 = {};",
+        container_chain_para_id
+    );
+    println!("/// Para id of the orchestrator chain whose head is proved in `ENCODED_PROOFS`");
+    println!(
+        "pub const ORCHESTRATOR_PARA_ID: u32 = {};",
+        orchestrator_para_id
+    );
+    println!();
+    println!("pub const ENCODED_PROOFS: &[(u32, (&str, &[&str]), (&str, &[&str]))] = &[");
+
+    let container_chain_para_id = ParaId::from(container_chain_para_id);
+    let orchestrator_para_id = ParaId::from(orchestrator_para_id);
+
+    // The authorities are 32 bytes long like a `NimbusId`, and made of distinct `u64`s so that
+    // they can also be decoded by the mock runtime
+    let authority = |i: u64| {
+        let mut authority = [0u8; 32];
+        for (j, chunk) in authority.chunks_mut(8).enumerate() {
+            chunk.copy_from_slice(&(4 * i + j as u64 + 1).to_le_bytes());
+        }
+        authority
+    };
+    let assignment_of = |authorities: Vec<[u8; 32]>| AssignedCollators {
+        orchestrator_chain: vec![],
+        container_chains: [(container_chain_para_id, authorities)]
+            .into_iter()
+            .collect(),
+    };
+
+    // One proof per number of authorities, with everything the inherent reads in the worst case:
+    // the assignments of the current and next sessions, the registrar state of the container
+    // chain, the session keys of every authority, the relay slot and epoch randomness
+    for x in 0u32..=20 {
+        let authorities: Vec<_> = (0..u64::from(x)).map(authority).collect();
+        let next_authorities = (u64::from(x)..u64::from(2 * x)).map(authority).collect();
+
+        let mut extra_storage = vec![
+            (
+                dp_core::well_known_keys::REGISTERED_PARA_IDS.to_vec(),
+                vec![container_chain_para_id].encode(),
+            ),
+            (
+                dp_core::well_known_keys::PAUSED_PARA_IDS.to_vec(),
+                Vec::<ParaId>::new().encode(),
+            ),
+        ];
+        for (i, authority) in authorities.iter().enumerate() {
+            let owner = [0x80 + i as u8; 32];
+            extra_storage.push((
+                dp_core::well_known_keys::session_key_owner(
+                    nimbus_primitives::NIMBUS_KEY_ID,
+                    authority,
+                ),
+                owner.to_vec(),
+            ));
+            extra_storage.push((
+                dp_core::well_known_keys::session_next_keys(&owner),
+                vec![i as u8 + 1; 64],
+            ));
+        }
+        let assignment = AuthorityAssignmentSproofBuilder::<[u8; 32]> {
+            session_index,
+            authority_assignment: assignment_of(authorities),
+            next_authority_assignment: Some(assignment_of(next_authorities)),
+            extra_storage,
+        };
+        let (root_b, proof_b) = assignment.into_state_root_and_proof();
+
+        let mut sproof_builder = test_relay_sproof_builder::ParaHeaderSproofBuilder::default();
+        let mut s = ParaHeaderSproofBuilderItem::default();
+        s.para_id = orchestrator_para_id;
+        // The header is kept minimal, its digest logs are an unbounded vec
+        let header = HeaderAs::NonEncoded(dp_core::Header {
+            parent_hash: Default::default(),
+            number: Default::default(),
+            state_root: root_b,
+            extrinsics_root: Default::default(),
+            digest: sp_runtime::generic::Digest { logs: vec![] },
+        });
+        s.author_id = header;
+        sproof_builder.items.push(s);
+        sproof_builder.extra_storage = vec![
+            (
+                dp_core::well_known_keys::RELAY_CURRENT_SLOT.to_vec(),
+                100u64.encode(),
+            ),
+            (
+                dp_core::well_known_keys::RELAY_EPOCH_INDEX.to_vec(),
+                1u64.encode(),
+            ),
+            (
+                dp_core::well_known_keys::RELAY_EPOCH_RANDOMNESS.to_vec(),
+                [7u8; 32].encode(),
+            ),
+            (
+                dp_core::well_known_keys::registrar_para_info(container_chain_para_id),
+                ParaInfo {
+                    manager: [0u8; 32],
+                    deposit: 100u128,
+                    locked: Some(false),
+                }
+                .encode(),
+            ),
+        ];
+
+        let (root_a, proof_a) = sproof_builder.into_state_root_and_proof();
+
+        println!("    ({}, (\"{}\", &[", x, hex::encode(root_a));
+
+        for x in proof_a.iter_nodes() {
+            println!("        \"{}\",", hex::encode(x));
+        }
+
+        println!("    ]), (");
+
+        println!("         \"{}\", &[", hex::encode(root_b));
+
+        for x in proof_b.iter_nodes() {
+            println!("            \"{}\",", hex::encode(x));
+        }
+
+        println!("        ])),");
+    }
+
+    println!("];")
+}
+
+#[test]
+fn test_set_authorities() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
+            1 => {
+                let mut s = ParaHeaderSproofBuilderItem::default();
+                s.para_id = OrchestratorParachainId::get();
+                s.author_id =
+                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                        parent_hash: Default::default(),
+                        number: Default::default(),
+                        state_root: orchestrator_chain_root,
+                        extrinsics_root: Default::default(),
+                        digest: sp_runtime::generic::Digest { logs: vec![] },
+                    });
+                sproof.items.push(s);
+            }
+            _ => unreachable!(),
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            let authorities = vec![4, 5];
+            assert_ok!(AuthoritiesNoting::set_authorities(
+                RuntimeOrigin::root(),
+                authorities.clone()
+            ));
+            assert_eq!(Authorities::<Test>::get(), authorities);
+            System::assert_last_event(Event::AuthoritiesInserted { authorities }.into());
+        });
+}
+
+fn solochain_assignment_proof(session_index: u32) -> (H256, sp_trie::StorageProof) {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = session_index;
+
+    assignment.into_state_root_and_proof_solochain()
+}
+
+#[test]
+fn test_authorities_override_expires_after_duration() {
+    let (relay_root, relay_chain_state) = solochain_assignment_proof(5);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_ok!(AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::root(),
+                vec![4u64],
+                2,
+                None
+            ));
+            System::assert_last_event(
+                Event::AuthoritiesOverrideStarted {
+                    authorities: vec![4u64],
+                    expires_at: 3,
+                    until_session: None,
+                }
+                .into(),
+            );
+            assert_eq!(AuthoritiesNoting::authorities(), vec![4u64]);
+            assert_eq!(AuthoritiesNoting::expected_author(1), Some(4u64));
+        })
+        .add(2, || {
+            // The inherent keeps noting the orchestrator authorities underneath
+            assert_eq!(Authorities::<Test>::get(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::authorities(), vec![4u64]);
+        })
+        .add(3, || {
+            System::assert_has_event(Event::AuthoritiesOverrideExpired.into());
+            assert_eq!(AuthoritiesNoting::authorities_override(), None);
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(
+                AuthoritiesChangedCalls::get().last(),
+                Some(&(vec![4u64], vec![10u64, 11u64], Some(5)))
+            );
+        });
+}
+
+#[test]
+fn test_authorities_override_expires_at_session() {
+    let (relay_root, relay_chain_state) = solochain_assignment_proof(5);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_ok!(AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::root(),
+                vec![4u64],
+                100,
+                Some(5)
+            ));
+            assert_eq!(AuthoritiesNoting::authorities(), vec![4u64]);
+        })
+        .add(2, || {
+            // Session 5 was noted by the inherent of this block
+            System::assert_has_event(Event::AuthoritiesOverrideExpired.into());
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        });
+}
+
+#[test]
+fn test_clear_authorities_override() {
+    let (relay_root, relay_chain_state) = solochain_assignment_proof(5);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_noop!(
+                AuthoritiesNoting::clear_authorities_override(RuntimeOrigin::root()),
+                Error::<Test>::NoAuthoritiesOverride
+            );
+            assert_ok!(AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::root(),
+                vec![4u64],
+                100,
+                None
+            ));
+            assert_noop!(
+                AuthoritiesNoting::clear_authorities_override(RuntimeOrigin::signed(1)),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(AuthoritiesNoting::clear_authorities_override(
+                RuntimeOrigin::root()
+            ));
+            System::assert_last_event(Event::AuthoritiesOverrideCleared.into());
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        });
+}
+
+#[test]
+fn test_invalid_authorities_override() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AuthoritiesNoting::set_authorities_override(RuntimeOrigin::root(), vec![], 10, None),
+            Error::<Test>::InvalidAuthoritiesOverride
+        );
+        assert_noop!(
+            AuthoritiesNoting::set_authorities_override(RuntimeOrigin::root(), vec![4u64], 0, None),
+            Error::<Test>::InvalidAuthoritiesOverride
+        );
+        assert_noop!(
+            AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::root(),
+                vec![1u64, 2, 3, 4, 5, 6],
+                10,
+                None
+            ),
+            Error::<Test>::TooManyAuthorities
+        );
+        assert_noop!(
+            AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::signed(1),
+                vec![4u64],
+                10,
+                None
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_on_authorities_changed() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 5;
+
+    let (relay_root, relay_chain_state) = assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            let call = (vec![], vec![10u64, 11u64], Some(5));
+            assert_eq!(AuthoritiesChangedCalls::get(), vec![call.clone(), call]);
+        })
+        .add(2, || {
+            // Same authorities, listeners are not called again
+            assert_eq!(AuthoritiesChangedCalls::get().len(), 2);
+
+            assert_ok!(AuthoritiesNoting::set_authorities(
+                RuntimeOrigin::root(),
+                vec![10u64, 11u64]
+            ));
+            assert_eq!(AuthoritiesChangedCalls::get().len(), 2);
+
+            assert_ok!(AuthoritiesNoting::set_authorities(
+                RuntimeOrigin::root(),
+                vec![4u64]
+            ));
+            let call = (vec![10u64, 11u64], vec![4u64], Some(5));
+            assert_eq!(AuthoritiesChangedCalls::get()[2..], [call.clone(), call]);
+        })
+        .add(3, || {
+            let call = (vec![4u64], vec![10u64, 11u64], Some(5));
+            assert_eq!(AuthoritiesChangedCalls::get()[4..], [call.clone(), call]);
+        });
+}
+
+#[test]
+fn test_set_authorities_during_override() {
+    let (relay_root, relay_chain_state) = solochain_assignment_proof(5);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_ok!(AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::root(),
+                vec![4u64],
+                10,
+                None
+            ));
+            assert_eq!(AuthoritiesChangedCalls::get().len(), 4);
+
+            // The overridden authorities are still in use, so listeners are not called
+            assert_ok!(AuthoritiesNoting::set_authorities(
+                RuntimeOrigin::root(),
+                vec![12u64]
+            ));
+            assert_eq!(AuthoritiesNoting::authorities(), vec![4u64]);
+            assert_eq!(AuthoritiesChangedCalls::get().len(), 4);
+
+            assert_ok!(AuthoritiesNoting::clear_authorities_override(
+                RuntimeOrigin::root()
+            ));
+            let call = (vec![4u64], vec![12u64], Some(5));
+            assert_eq!(AuthoritiesChangedCalls::get()[4..], [call.clone(), call]);
+        });
+}
+
+#[test]
+fn test_authorities_override_until_noted_session() {
+    let (relay_root, relay_chain_state) = solochain_assignment_proof(5);
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            for until_session in [4, 5] {
+                assert_noop!(
+                    AuthoritiesNoting::set_authorities_override(
+                        RuntimeOrigin::root(),
+                        vec![4u64],
+                        10,
+                        Some(until_session)
+                    ),
+                    Error::<Test>::InvalidAuthoritiesOverride
+                );
+            }
+            assert_ok!(AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::root(),
+                vec![4u64],
+                10,
+                Some(6)
+            ));
+        });
+}
+
+fn sorted_block_production_stats(session_index: u32) -> Vec<(u64, BlockProductionStats)> {
+    let mut stats = AuthoritiesNoting::block_production_stats(session_index);
+    stats.sort_by_key(|(author, _)| *author);
+    stats
+}
+
+fn block_production_test() -> BlockTests {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 5;
+
+    let (relay_root, relay_chain_state) = assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+}
+
+#[test]
+fn test_block_production_stats() {
+    // The mock uses the block number as slot, and blocks are authored by the expected author
+    block_production_test()
+        .add(1, || {})
+        .add(2, || {
+            assert_eq!(
+                sorted_block_production_stats(5),
+                vec![(
+                    11,
+                    BlockProductionStats {
+                        authored: 1,
+                        missed: 0
+                    }
+                )]
+            );
+        })
+        .add(5, || {
+            // Slots 3 and 4 were missed
+            assert_eq!(
+                sorted_block_production_stats(5),
+                vec![
+                    (
+                        10,
+                        BlockProductionStats {
+                            authored: 1,
+                            missed: 1
+                        }
+                    ),
+                    (
+                        11,
+                        BlockProductionStats {
+                            authored: 1,
+                            missed: 1
+                        }
+                    ),
+                ]
+            );
+        })
+        .add(6, || {
+            assert_eq!(
+                sorted_block_production_stats(5),
+                vec![
+                    (
+                        10,
+                        BlockProductionStats {
+                            authored: 1,
+                            missed: 1
+                        }
+                    ),
+                    (
+                        11,
+                        BlockProductionStats {
+                            authored: 2,
+                            missed: 1
+                        }
+                    ),
+                ]
+            );
+            assert!(AuthoritiesNoting::block_production_stats(4).is_empty());
+        });
+}
+
+#[test]
+fn test_missed_slots_are_capped() {
+    block_production_test().add(1, || {}).add(20, || {
+        // Only the last MaxAuthorities (5) slots, 15 to 19, are attributed
+        assert_eq!(
+            sorted_block_production_stats(5),
+            vec![
+                (
+                    10,
+                    BlockProductionStats {
+                        authored: 0,
+                        missed: 2
+                    }
+                ),
+                (
+                    11,
+                    BlockProductionStats {
+                        authored: 1,
+                        missed: 3
+                    }
+                ),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_set_authorities_over_max_authorities() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AuthoritiesNoting::set_authorities(RuntimeOrigin::root(), (0u64..6).collect()),
+            Error::<Test>::TooManyAuthorities
+        );
+    });
+}
+
+#[test]
+fn test_set_authorities_with_duplicates() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AuthoritiesNoting::set_authorities(RuntimeOrigin::root(), vec![1u64, 2, 1]),
+            Error::<Test>::DuplicateAuthorities
+        );
+        assert_noop!(
+            AuthoritiesNoting::set_authorities_override(
+                RuntimeOrigin::root(),
+                vec![4u64, 4],
+                10,
+                None
+            ),
+            Error::<Test>::DuplicateAuthorities
+        );
+    });
+}
+
+#[test]
+fn test_authorities_over_max_authorities_are_truncated() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), (10u64..17).collect());
+    assignment.session_index = 3;
+
+    let (relay_root, relay_chain_state) = assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            // MaxAuthorities is 5 in the mock
+            assert_eq!(
+                AuthoritiesNoting::authorities(),
+                vec![10u64, 11u64, 12u64, 13u64, 14u64]
+            );
+            assert_eq!(
+                AuthoritiesNoting::authorities_for_session(3),
+                Some(vec![10u64, 11u64, 12u64, 13u64, 14u64])
+            );
+            System::assert_has_event(
+                Event::AuthoritiesTruncated {
+                    session_index: 3,
+                    assigned: 7,
+                }
+                .into(),
+            );
+        });
+}
+
+/// Writes the authorities as an unbounded vector and resets the storage version, as in chains
+/// deployed before the storage was bounded
+fn put_unbounded_authorities(authorities: &[u64]) {
+    StorageVersion::new(0).put::<AuthoritiesNoting>();
+    frame_support::storage::unhashed::put(
+        &Authorities::<Test>::hashed_key(),
+        &authorities.to_vec(),
+    );
+}
+
+#[test]
+fn test_migrate_to_bounded_authorities() {
+    new_test_ext().execute_with(|| {
+        put_unbounded_authorities(&[0, 1, 2, 3, 4, 5, 6]);
+
+        MigrateToBoundedAuthorities::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            AuthoritiesNoting::on_chain_storage_version(),
+            migrations::STORAGE_VERSION
+        );
+        assert_eq!(AuthoritiesNoting::authorities(), vec![0, 1, 2, 3, 4]);
+    });
+}
+
+#[test]
+fn test_migrate_to_bounded_authorities_runs_once() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<AuthoritiesNoting>();
+        let unbounded: Vec<u64> = (0..7).collect();
+        frame_support::storage::unhashed::put(&Authorities::<Test>::hashed_key(), &unbounded);
+
+        MigrateToBoundedAuthorities::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            frame_support::storage::unhashed::get::<Vec<u64>>(&Authorities::<Test>::hashed_key()),
+            Some(unbounded)
+        );
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn test_migrate_to_bounded_authorities_try_runtime() {
+    new_test_ext().execute_with(|| {
+        put_unbounded_authorities(&[0, 1, 2, 3, 4, 5, 6]);
+
+        assert_ok!(MigrateToBoundedAuthorities::<Test>::try_on_runtime_upgrade(
+            true
+        ));
+    });
+}
+
+#[test]
+fn test_set_orchestrator_para_id() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| match relay_block_num {
+            1 => {
+                let mut s = ParaHeaderSproofBuilderItem::default();
+                s.para_id = OrchestratorParachainId::get();
+                s.author_id =
+                    HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                        parent_hash: Default::default(),
+                        number: Default::default(),
+                        state_root: orchestrator_chain_root,
+                        extrinsics_root: Default::default(),
+                        digest: sp_runtime::generic::Digest { logs: vec![] },
+                    });
+                sproof.items.push(s);
+            }
+            _ => unreachable!(),
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            let new_para_id = ParaId::new(2000);
+            assert_ok!(AuthoritiesNoting::set_orchestrator_para_id(
+                RuntimeOrigin::root(),
+                new_para_id
+            ));
+            assert_eq!(OrchestratorParaId::<Test>::get(), new_para_id);
+            System::assert_last_event(
+                Event::OrchestratorParachainIdUpdated {
+                    new_para_id: 2000.into(),
+                }
+                .into(),
+            );
+        });
+}
+
+#[test]
+fn test_set_chain_mode() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(AuthoritiesNoting::chain_mode(), None);
+
+        assert_ok!(AuthoritiesNoting::set_chain_mode(
+            RuntimeOrigin::root(),
+            Some(OrchestratorChainMode::Solochain)
+        ));
+        assert_eq!(
+            AuthoritiesNoting::chain_mode(),
+            Some(OrchestratorChainMode::Solochain)
+        );
+        System::assert_last_event(
+            Event::ChainModeUpdated {
+                new_mode: Some(OrchestratorChainMode::Solochain),
+            }
+            .into(),
+        );
+    });
+}
+
+fn chain_mode_test(solochain_proof: bool) -> BlockTests {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    if solochain_proof {
+        let (relay_root, relay_chain_state) = assignment.into_state_root_and_proof_solochain();
+
+        return BlockTests::new()
+            .with_relay_storage_proof(relay_root, relay_chain_state)
+            .with_orchestrator_storage_proof(sp_trie::StorageProof::empty());
+    }
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, _, sproof| {
+            let mut s = ParaHeaderSproofBuilderItem::default();
+            s.para_id = OrchestratorParachainId::get();
+            s.author_id = HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                parent_hash: Default::default(),
+                number: Default::default(),
+                state_root: orchestrator_chain_root,
+                extrinsics_root: Default::default(),
+                digest: sp_runtime::generic::Digest { logs: vec![] },
+            });
+            sproof.items.push(s);
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+}
+
+#[test]
+fn test_chain_mode_matching_proofs() {
+    for (solochain_proof, mode) in [
+        (true, OrchestratorChainMode::Solochain),
+        (false, OrchestratorChainMode::Parachain),
+    ] {
+        chain_mode_test(solochain_proof)
+            .add(1, move || {
+                ChainMode::<Test>::put(mode);
+                Authorities::<Test>::kill();
+            })
+            .add(2, || {
+                assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            })
+            .run();
+    }
+}
+
+#[test]
+fn test_chain_mode_mismatch() {
+    for (solochain_proof, mode) in [
+        (true, OrchestratorChainMode::Parachain),
+        (false, OrchestratorChainMode::Solochain),
+    ] {
+        chain_mode_test(solochain_proof)
+            .add(1, move || {
+                assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+                ChainMode::<Test>::put(mode);
+            })
+            .add(2, || {
+                assert!(AuthoritiesNoting::authorities().is_empty());
+                System::assert_has_event(
+                    Event::AuthoritiesNotingFailed {
+                        error: Error::<Test>::ChainModeMismatch.into(),
+                    }
+                    .into(),
+                );
+            })
+            .run();
+    }
+}
+
+#[test]
+fn test_chain_mode_switch_does_not_compare_sessions() {
+    chain_mode_test(false)
+        .add(1, || {
+            // Pretend a later session was noted from a solochain orchestrator
+            LastNotedOrigin::<Test>::mutate(|origin| {
+                let origin = origin.as_mut().unwrap();
+                origin.orchestrator_session_index = 6;
+                origin.orchestrator_block_number = None;
+                origin.orchestrator_block_hash = None;
+            });
+            Authorities::<Test>::put(BoundedVec::truncate_from(vec![12u64]));
+        })
+        .add(2, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(AuthoritiesNoting::last_noted_session(), Some(0));
+
+            assert_ok!(AuthoritiesNoting::set_chain_mode(
+                RuntimeOrigin::root(),
+                Some(OrchestratorChainMode::Parachain)
+            ));
+            assert_eq!(AuthoritiesNoting::last_noted_origin(), None);
+        });
+}
+
+#[test]
+fn test_orchestrator_session_going_backwards_is_rejected() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+    assignment.session_index = 5;
+
+    let (relay_root, relay_chain_state) = assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::last_noted_session(), Some(5));
+            // Pretend a newer session was noted
+            LastNotedOrigin::<Test>::mutate(|origin| {
+                origin.as_mut().unwrap().orchestrator_session_index = 6;
+            });
+            Authorities::<Test>::put(BoundedVec::truncate_from(vec![12u64]));
+        })
+        .add(2, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![12u64]);
+            assert_eq!(AuthoritiesNoting::last_noted_session(), Some(6));
+            System::assert_last_event(
+                Event::AuthoritiesNotingFailed {
+                    error: Error::<Test>::OrchestratorSessionWentBackwards.into(),
+                }
+                .into(),
+            );
+        });
+}
+
+#[test]
+fn test_orchestrator_header_going_backwards_is_rejected() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (orchestrator_chain_root, orchestrator_chain_state) =
+        assignment.into_state_root_and_proof();
+
+    BlockTests::new()
+        .with_relay_sproof_builder(move |_, relay_block_num, sproof| {
+            // The orchestrator head goes backwards in the third block
+            let number = match relay_block_num {
+                1 | 2 => 10,
+                3 => 9,
+                _ => unreachable!(),
+            };
+            let mut s = ParaHeaderSproofBuilderItem::default();
+            s.para_id = OrchestratorParachainId::get();
+            s.author_id = HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+                parent_hash: Default::default(),
+                number,
+                state_root: orchestrator_chain_root,
+                extrinsics_root: Default::default(),
+                digest: sp_runtime::generic::Digest { logs: vec![] },
+            });
+            sproof.items.push(s);
+        })
+        .with_orchestrator_storage_proof(orchestrator_chain_state)
+        .add(1, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+        })
+        .add(2, || {
+            // Noting the same orchestrator block again is fine
+            assert_eq!(AuthoritiesNoting::authorities(), vec![10u64, 11u64]);
+            assert_eq!(
+                AuthoritiesNoting::last_noted_origin().and_then(|o| o.orchestrator_block_number),
+                Some(10)
+            );
+            Authorities::<Test>::put(BoundedVec::truncate_from(vec![12u64]));
+        })
+        .add(3, || {
+            assert_eq!(AuthoritiesNoting::authorities(), vec![12u64]);
+            assert_eq!(
+                AuthoritiesNoting::last_noted_origin().and_then(|o| o.orchestrator_block_number),
+                Some(10)
+            );
+            System::assert_last_event(
+                Event::AuthoritiesNotingFailed {
+                    error: Error::<Test>::OrchestratorHeaderWentBackwards.into(),
+                }
+                .into(),
+            );
+        });
+}
+
+#[test]
+fn test_set_orchestrator_para_id_resets_last_noted_origin() {
+    let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+    assignment
+        .authority_assignment
+        .container_chains
+        .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+    let (relay_root, relay_chain_state) = assignment.into_state_root_and_proof_solochain();
+
+    BlockTests::new()
+        .with_relay_storage_proof(relay_root, relay_chain_state)
+        .with_orchestrator_storage_proof(sp_trie::StorageProof::empty())
+        .add(1, || {
+            assert!(AuthoritiesNoting::last_noted_origin().is_some());
+            assert_ok!(AuthoritiesNoting::set_orchestrator_para_id(
+                RuntimeOrigin::root(),
+                ParaId::new(2000)
+            ));
+            assert_eq!(AuthoritiesNoting::last_noted_origin(), None);
+        });
+}
+
+#[test]
+fn test_try_state() {
+    new_test_ext().execute_with(|| {
+        Authorities::<Test>::put(BoundedVec::truncate_from(vec![10u64, 11u64]));
+
+        // Genesis does not note authorities
+        assert_ok!(AuthoritiesNoting::do_try_state(0));
+        assert_eq!(
+            AuthoritiesNoting::do_try_state(1),
+            Err("DidSetOrchestratorAuthorityData must be set after every block".into())
+        );
+
+        DidSetOrchestratorAuthorityData::<Test>::put(true);
+        assert_ok!(AuthoritiesNoting::do_try_state(1));
+
+        DidSetOrchestratorAuthorityData::<Test>::put(false);
+        assert_eq!(
+            AuthoritiesNoting::do_try_state(1),
+            Err("DidSetOrchestratorAuthorityData must not be stored as false".into())
+        );
+        DidSetOrchestratorAuthorityData::<Test>::put(true);
+
+        Authorities::<Test>::put(BoundedVec::truncate_from(vec![10u64, 11u64, 10u64]));
+        assert_eq!(
+            AuthoritiesNoting::do_try_state(1),
+            Err("Authorities must not contain duplicates".into())
+        );
+        Authorities::<Test>::put(BoundedVec::truncate_from(vec![10u64, 11u64]));
+
+        OrchestratorParaId::<Test>::put(ParachainId::get());
+        assert_eq!(
+            AuthoritiesNoting::do_try_state(1),
+            Err("OrchestratorParaId must not be the para id of this chain".into())
+        );
+    });
+}
+
+#[test]
+fn weights_assigned_to_extrinsics_are_correct() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            crate::Call::<Test>::set_authorities {
+                authorities: vec![]
+            }
+            .get_dispatch_info()
+            .call_weight,
+            <() as crate::weights::WeightInfo>::set_authorities(0u32)
+                .saturating_add(<Test as Config>::OnAuthoritiesChanged::max_weight())
+        );
+
+        assert_eq!(
+            crate::Call::<Test>::set_orchestrator_para_id {
+                new_para_id: 1u32.into()
+            }
+            .get_dispatch_info()
+            .call_weight,
+            <() as crate::weights::WeightInfo>::set_orchestrator_para_id()
+        );
+
+        assert_eq!(
+            crate::Call::<Test>::set_chain_mode { new_mode: None }
+                .get_dispatch_info()
+                .call_weight,
+            <() as crate::weights::WeightInfo>::set_chain_mode()
+        );
+
+        let mut assignment = AuthorityAssignmentSproofBuilder::<u64>::default();
+        assignment
+            .authority_assignment
+            .container_chains
+            .insert(ParachainId::get(), vec![10u64, 11u64]);
+
+        let (orchestrator_chain_root, orchestrator_chain_state) =
+            assignment.into_state_root_and_proof();
+
+        let mut sproof_builder = ParaHeaderSproofBuilder::default();
+
+        let mut s = ParaHeaderSproofBuilderItem::default();
+        s.para_id = OrchestratorParachainId::get();
+        s.author_id = HeaderAs::NonEncoded(sp_runtime::generic::Header::<u32, BlakeTwo256> {
+            parent_hash: Default::default(),
+            number: Default::default(),
+            state_root: orchestrator_chain_root,
+            extrinsics_root: Default::default(),
+            digest: sp_runtime::generic::Digest { logs: vec![] },
+        });
+        sproof_builder.items.push(s);
+
+        let (relay_root, relay_chain_state) = sproof_builder.into_state_root_and_proof();
+        frame_support::storage::unhashed::put(MOCK_RELAY_ROOT_KEY, &relay_root);
+
+        let relay_proof_size = relay_chain_state.encoded_size() as u32;
+        let orchestrator_proof_size = orchestrator_chain_state.encoded_size() as u32;
+
+        let mut inherent_data = InherentData::default();
+        let system_inherent_data =
+            ccp_authorities_noting_inherent::ContainerChainAuthoritiesInherentData {
+                relay_chain_state,
                 orchestrator_chain_state: orchestrator_chain_state.clone(),
             };
         inherent_data
@@ -368,14 +2549,33 @@ fn weights_assigned_to_extrinsics_are_correct() {
                 &system_inherent_data,
             )
             .expect("failed to put VFP inherent");
-        let inherent_weight = AuthoritiesNoting::create_inherent(&inherent_data)
-            .expect("got an inherent")
+        let inherent = AuthoritiesNoting::create_inherent(&inherent_data).expect("got an inherent");
+
+        // The weight is charged for the maximum number of authorities, and refunded once the
+        // actual assignment is known
+        assert_eq!(
+            inherent.get_dispatch_info().call_weight,
+            <() as crate::weights::WeightInfo>::set_latest_authorities_data(
+                relay_proof_size,
+                orchestrator_proof_size,
+                5
+            )
+            .saturating_add(<Test as Config>::OnAuthoritiesChanged::max_weight())
+        );
+
+        let inherent_weight = inherent
             .dispatch_bypass_filter(RawOrigin::None.into())
             .expect("dispatch succeeded");
 
+        // Both handlers of the mock are called, as the authorities changed
         assert_eq!(
             inherent_weight.actual_weight.unwrap(),
-            <() as crate::weights::WeightInfo>::set_latest_authorities_data()
+            <() as crate::weights::WeightInfo>::set_latest_authorities_data(
+                relay_proof_size,
+                orchestrator_proof_size,
+                2
+            )
+            .saturating_add(AuthoritiesChangedWeight::get() * 2)
         );
     });
 }
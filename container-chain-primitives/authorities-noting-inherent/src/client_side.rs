@@ -19,8 +19,12 @@ use {
     cumulus_primitives_core::{relay_chain::HeadData, ParaId},
     cumulus_relay_chain_interface::{PHash, RelayChainInterface},
     dc_orchestrator_chain_interface::OrchestratorChainInterface,
+    dp_collator_assignment::AssignedCollators,
     dp_core::{well_known_keys, Header as OrchestratorHeader},
+    nimbus_primitives::{NimbusId, NIMBUS_KEY_ID},
     parity_scale_codec::Decode,
+    sp_core::crypto::ByteArray,
+    std::future::Future,
 };
 
 const LOG_TARGET: &str = "parachain-inherent";
@@ -31,9 +35,11 @@ async fn collect_relay_storage_proof(
     relay_chain_interface: &impl RelayChainInterface,
     orchestrator_para_id: ParaId,
     relay_parent: PHash,
+    container_para_id: Option<ParaId>,
 ) -> Option<sp_state_machine::StorageProof> {
     let mut relevant_keys = Vec::new();
     relevant_keys.push(well_known_keys::para_id_head(orchestrator_para_id));
+    relevant_keys.extend(relay_chain_keys(container_para_id));
 
     relay_chain_interface
         .prove_read(relay_parent, &relevant_keys)
@@ -41,11 +47,87 @@ async fn collect_relay_storage_proof(
         .ok()
 }
 
+/// Keys of the relay chain state that are proved along with the authorities in both modes,
+/// including the registration of the container chain in the relay registrar if its para id is
+/// known
+fn relay_chain_keys(container_para_id: Option<ParaId>) -> Vec<Vec<u8>> {
+    let mut keys = vec![
+        well_known_keys::RELAY_CURRENT_SLOT.to_vec(),
+        well_known_keys::RELAY_EPOCH_INDEX.to_vec(),
+        well_known_keys::RELAY_EPOCH_RANDOMNESS.to_vec(),
+    ];
+    if let Some(para_id) = container_para_id {
+        keys.push(well_known_keys::registrar_para_info(para_id));
+    }
+
+    keys
+}
+
+/// Keys of the orchestrator registrar state of the container chain that are proved along with
+/// its authorities, if the container chain para id is known
+fn container_chain_keys(container_para_id: Option<ParaId>, solochain: bool) -> Vec<Vec<u8>> {
+    if container_para_id.is_none() {
+        return vec![];
+    }
+
+    let (registered_para_ids, paused_para_ids) = if solochain {
+        (
+            well_known_keys::SOLOCHAIN_REGISTERED_PARA_IDS,
+            well_known_keys::SOLOCHAIN_PAUSED_PARA_IDS,
+        )
+    } else {
+        (
+            well_known_keys::REGISTERED_PARA_IDS,
+            well_known_keys::PAUSED_PARA_IDS,
+        )
+    };
+
+    vec![registered_para_ids.to_vec(), paused_para_ids.to_vec()]
+}
+
+/// Keys of the session pallet of the orchestrator mapping the authorities assigned to
+/// `container_para_id` in `assignment_key` to their owner, and each owner to all its session
+/// keys. Empty if the container chain para id is not known.
+async fn authority_session_keys<F, Fut>(
+    read_storage: F,
+    assignment_key: &[u8],
+    container_para_id: Option<ParaId>,
+) -> Vec<Vec<u8>>
+where
+    F: Fn(Vec<u8>) -> Fut,
+    Fut: Future<Output = Option<Vec<u8>>>,
+{
+    let Some(para_id) = container_para_id else {
+        return vec![];
+    };
+
+    let Some(authorities) = read_storage(assignment_key.to_vec())
+        .await
+        .and_then(|raw| AssignedCollators::<NimbusId>::decode(&mut raw.as_slice()).ok())
+        .and_then(|mut assignment| assignment.container_chains.remove(&para_id))
+    else {
+        return vec![];
+    };
+
+    let mut keys = Vec::new();
+    for authority in authorities {
+        let key_owner = well_known_keys::session_key_owner(NIMBUS_KEY_ID, authority.as_slice());
+        // The owner is stored encoded, which is how the next keys are keyed
+        if let Some(owner) = read_storage(key_owner.clone()).await {
+            keys.push(well_known_keys::session_next_keys(&owner));
+        }
+        keys.push(key_owner);
+    }
+
+    keys
+}
+
 /// Collect the relevant orchestrator chain state in form of a proof
 /// for putting it into the authorities noting inherent
 async fn collect_orchestrator_storage_proof(
     orchestrator_chain_interface: &impl OrchestratorChainInterface,
     orchestrator_parent: PHash,
+    container_para_id: Option<ParaId>,
 ) -> Option<sp_state_machine::StorageProof> {
     // We need to fetch the actual session index to build the key for the
     // authorities.
@@ -55,12 +137,32 @@ async fn collect_orchestrator_storage_proof(
         .ok()??;
     let session_index = u32::decode(&mut session_index.as_slice()).ok()?;
 
+    let assignment_key = well_known_keys::authority_assignment_for_session(session_index, None);
+
     let mut relevant_keys = Vec::new();
     relevant_keys.push(well_known_keys::SESSION_INDEX.to_vec());
+    relevant_keys.push(assignment_key.clone());
+    // Also prove the assignment for the next session, so the container chain can prepare for
+    // the rotation before it happens
     relevant_keys.push(well_known_keys::authority_assignment_for_session(
-        session_index,
+        session_index.saturating_add(1),
         None,
     ));
+    relevant_keys.extend(container_chain_keys(container_para_id, false));
+    relevant_keys.extend(
+        authority_session_keys(
+            |key| async move {
+                orchestrator_chain_interface
+                    .get_storage_by_key(orchestrator_parent, &key)
+                    .await
+                    .ok()
+                    .flatten()
+            },
+            &assignment_key,
+            container_para_id,
+        )
+        .await,
+    );
 
     orchestrator_chain_interface
         .prove_read(orchestrator_parent, &relevant_keys)
@@ -73,6 +175,7 @@ async fn collect_orchestrator_storage_proof(
 async fn collect_solochain_storage_proof(
     orchestrator_chain_interface: &impl RelayChainInterface,
     orchestrator_parent: PHash,
+    container_para_id: Option<ParaId>,
 ) -> Option<sp_state_machine::StorageProof> {
     // We need to fetch the actual session index to build the key for the
     // authorities.
@@ -82,12 +185,34 @@ async fn collect_solochain_storage_proof(
         .ok()??;
     let session_index = u32::decode(&mut session_index.as_slice()).ok()?;
 
+    let assignment_key = well_known_keys::authority_assignment_for_session(
+        session_index,
+        Some(well_known_keys::SOLOCHAIN_AUTHORITY_ASSIGNMENT_PREFIX),
+    );
+
     let mut relevant_keys = Vec::new();
     relevant_keys.push(well_known_keys::SESSION_INDEX.to_vec());
+    relevant_keys.push(assignment_key.clone());
     relevant_keys.push(well_known_keys::authority_assignment_for_session(
-        session_index,
+        session_index.saturating_add(1),
         Some(well_known_keys::SOLOCHAIN_AUTHORITY_ASSIGNMENT_PREFIX),
     ));
+    relevant_keys.extend(container_chain_keys(container_para_id, true));
+    relevant_keys.extend(
+        authority_session_keys(
+            |key| async move {
+                orchestrator_chain_interface
+                    .get_storage_by_key(orchestrator_parent, &key)
+                    .await
+                    .ok()
+                    .flatten()
+            },
+            &assignment_key,
+            container_para_id,
+        )
+        .await,
+    );
+    relevant_keys.extend(relay_chain_keys(container_para_id));
 
     orchestrator_chain_interface
         .prove_read(orchestrator_parent, &relevant_keys)
@@ -105,9 +230,35 @@ impl ContainerChainAuthoritiesInherentData {
         orchestrator_chain_interface: &impl OrchestratorChainInterface,
         orchestrator_para_id: ParaId,
     ) -> Option<ContainerChainAuthoritiesInherentData> {
-        let relay_chain_state =
-            collect_relay_storage_proof(relay_chain_interface, orchestrator_para_id, relay_parent)
-                .await?;
+        Self::create_at_for_para(
+            relay_parent,
+            relay_chain_interface,
+            orchestrator_chain_interface,
+            orchestrator_para_id,
+            None,
+        )
+        .await
+    }
+
+    /// Create the [`ContainerChainAuthoritiesInherentData`] at the given `relay_parent`, also
+    /// proving the registration and status of `container_para_id` and the orchestrator session
+    /// keys of its authorities if set.
+    ///
+    /// Returns `None` if the creation failed.
+    pub async fn create_at_for_para(
+        relay_parent: PHash,
+        relay_chain_interface: &impl RelayChainInterface,
+        orchestrator_chain_interface: &impl OrchestratorChainInterface,
+        orchestrator_para_id: ParaId,
+        container_para_id: Option<ParaId>,
+    ) -> Option<ContainerChainAuthoritiesInherentData> {
+        let relay_chain_state = collect_relay_storage_proof(
+            relay_chain_interface,
+            orchestrator_para_id,
+            relay_parent,
+            container_para_id,
+        )
+        .await?;
 
         let header_orchestrator = relay_chain_interface
             .get_storage_by_key(
@@ -153,6 +304,7 @@ impl ContainerChainAuthoritiesInherentData {
         let orchestrator_chain_state = collect_orchestrator_storage_proof(
             orchestrator_chain_interface,
             orchestrator_header.hash(),
+            container_para_id,
         )
         .await?;
 
@@ -168,9 +320,23 @@ impl ContainerChainAuthoritiesInherentData {
     pub async fn create_at_solochain(
         relay_parent: PHash,
         relay_chain_interface: &impl RelayChainInterface,
+    ) -> Option<ContainerChainAuthoritiesInherentData> {
+        Self::create_at_solochain_for_para(relay_parent, relay_chain_interface, None).await
+    }
+
+    /// Create the [`ContainerChainAuthoritiesInherentData`] at the given `relay_parent`, also
+    /// proving the registration and status of `container_para_id` and the orchestrator session
+    /// keys of its authorities if set.
+    ///
+    /// Returns `None` if the creation failed.
+    pub async fn create_at_solochain_for_para(
+        relay_parent: PHash,
+        relay_chain_interface: &impl RelayChainInterface,
+        container_para_id: Option<ParaId>,
     ) -> Option<ContainerChainAuthoritiesInherentData> {
         let relay_chain_state =
-            collect_solochain_storage_proof(relay_chain_interface, relay_parent).await?;
+            collect_solochain_storage_proof(relay_chain_interface, relay_parent, container_para_id)
+                .await?;
 
         Some(ContainerChainAuthoritiesInherentData {
             relay_chain_state,
@@ -136,12 +136,15 @@ impl MockAuthoritiesNotingInherentDataProvider {
 
         let container_chains =
             BTreeMap::from_iter([(self.container_para_id, self.authorities.clone())]);
+        let authority_assignment = AssignedCollators {
+            orchestrator_chain: vec![],
+            container_chains,
+        };
         let assignment = AuthorityAssignmentSproofBuilder::<NimbusId> {
-            authority_assignment: AssignedCollators {
-                orchestrator_chain: vec![],
-                container_chains,
-            },
+            next_authority_assignment: Some(authority_assignment.clone()),
+            authority_assignment,
             session_index: 0,
+            extra_storage: vec![],
         };
 
         let (orchestrator_chain_root, orchestrator_chain_state) =
@@ -32,11 +32,13 @@ use {
         BlockNumber, ContainerChainGenesisData, DataPreserverAssignment, DataPreserverProfileId,
         OrchestratorChainInterface, OrchestratorChainResult,
     },
+    dp_collator_assignment::AssignedCollators,
     dp_core::{well_known_keys, Header as OrchestratorHeader},
     futures::Stream,
     nimbus_primitives::NimbusId,
     polkadot_overseer::Handle,
     sc_client_api::{HeaderBackend, StorageKey, StorageProvider},
+    sp_core::crypto::ByteArray,
     sp_inherents::{InherentData, InherentDataProvider},
     sp_state_machine::{prove_read, StorageValue},
     sp_version::RuntimeVersion,
@@ -62,10 +64,17 @@ struct DummyRelayChainInterface {
 
 impl DummyOrchestratorChainInterface {
     fn new(session: u32) -> Self {
+        Self::new_with_storage(session, vec![])
+    }
+
+    fn new_with_storage(session: u32, storage: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
         let builder = TestClientBuilder::new().add_extra_storage(
             well_known_keys::SESSION_INDEX.to_vec(),
             session.encode().to_vec(),
         );
+        let builder = storage.into_iter().fold(builder, |builder, (key, value)| {
+            builder.add_extra_storage(key, value)
+        });
 
         Self {
             orchestrator_client: Arc::new(builder.build()),
@@ -75,17 +84,35 @@ impl DummyOrchestratorChainInterface {
 
 impl DummyRelayChainInterface {
     fn new(orchestrator_para_id: ParaId, header: OrchestratorHeader) -> Self {
-        Self::new_with_head_data(
-            orchestrator_para_id,
+        Self::new_with_storage(orchestrator_para_id, header, vec![])
+    }
+
+    fn new_with_storage(
+        orchestrator_para_id: ParaId,
+        header: OrchestratorHeader,
+        mut storage: Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> Self {
+        storage.push((
+            well_known_keys::para_id_head(orchestrator_para_id).to_vec(),
             HeadData(header.encode()).encode().to_vec(),
-        )
+        ));
+
+        Self::from_storage(storage)
     }
 
     fn new_with_head_data(orchestrator_para_id: ParaId, head_data: Vec<u8>) -> Self {
-        let builder = TestClientBuilder::new().add_extra_storage(
+        Self::from_storage(vec![(
             well_known_keys::para_id_head(orchestrator_para_id).to_vec(),
             head_data,
-        );
+        )])
+    }
+
+    fn from_storage(storage: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+        let builder = storage
+            .into_iter()
+            .fold(TestClientBuilder::new(), |builder, (key, value)| {
+                builder.add_extra_storage(key, value)
+            });
 
         Self {
             relay_client: Arc::new(builder.build()),
@@ -93,6 +120,32 @@ impl DummyRelayChainInterface {
     }
 }
 
+/// Genesis header of `client`, converted to an orchestrator header
+fn genesis_header(client: &TestClient) -> OrchestratorHeader {
+    let header = client.header(client.genesis_hash()).unwrap().unwrap();
+
+    // The substrate example header is not the same as the tanssi one in the block num parameter
+    OrchestratorHeader {
+        parent_hash: header.parent_hash,
+        number: header.number.try_into().unwrap(),
+        state_root: header.state_root,
+        extrinsics_root: header.extrinsics_root,
+        digest: header.digest,
+    }
+}
+
+/// Value of `key` proved by `proof` against `root`, panicking if the proof does not include it
+fn proved_value(root: PHash, proof: &sp_trie::StorageProof, key: &[u8]) -> Option<Vec<u8>> {
+    sp_state_machine::read_proof_check::<sp_runtime::traits::BlakeTwo256, _>(
+        root,
+        proof.clone(),
+        [key],
+    )
+    .expect("key must be part of the proof")
+    .remove(key)
+    .flatten()
+}
+
 #[async_trait]
 impl OrchestratorChainInterface for DummyOrchestratorChainInterface {
     fn overseer_handle(&self) -> OrchestratorChainResult<Handle> {
@@ -586,3 +639,291 @@ async fn test_header_not_decodable() {
 
     assert_eq!(created, None);
 }
+
+#[tokio::test]
+async fn test_next_session_assignment_is_proved() {
+    let orch_session = 1u32;
+    let orch_para_id = 1000u32;
+    let current_assignment = AssignedCollators::<NimbusId> {
+        orchestrator_chain: vec![],
+        container_chains: BTreeMap::from_iter([(
+            2000.into(),
+            vec![NimbusId::from_slice(&[1u8; 32]).unwrap()],
+        )]),
+    };
+    let next_assignment = AssignedCollators::<NimbusId> {
+        orchestrator_chain: vec![],
+        container_chains: BTreeMap::from_iter([(
+            2000.into(),
+            vec![NimbusId::from_slice(&[2u8; 32]).unwrap()],
+        )]),
+    };
+    let next_assignment_key =
+        well_known_keys::authority_assignment_for_session(orch_session + 1, None);
+    let orchestrator_chain_interface = Arc::new(DummyOrchestratorChainInterface::new_with_storage(
+        orch_session,
+        vec![
+            (
+                well_known_keys::authority_assignment_for_session(orch_session, None),
+                current_assignment.encode(),
+            ),
+            (next_assignment_key.clone(), next_assignment.encode()),
+        ],
+    ));
+    let orchestrator_header = genesis_header(&orchestrator_chain_interface.orchestrator_client);
+    let relay_chain_interface = Arc::new(DummyRelayChainInterface::new(
+        orch_para_id.into(),
+        orchestrator_header.clone(),
+    ));
+    let relay_hash = relay_chain_interface.relay_client.genesis_hash();
+
+    let created = ContainerChainAuthoritiesInherentData::create_at(
+        relay_hash,
+        &relay_chain_interface,
+        &orchestrator_chain_interface,
+        orch_para_id.into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        proved_value(
+            orchestrator_header.state_root,
+            &created.orchestrator_chain_state,
+            &next_assignment_key,
+        ),
+        Some(next_assignment.encode())
+    );
+
+    // Same in solochain mode, where the assignment is part of the relay chain state
+    let next_assignment_key = well_known_keys::authority_assignment_for_session(
+        orch_session + 1,
+        Some(well_known_keys::SOLOCHAIN_AUTHORITY_ASSIGNMENT_PREFIX),
+    );
+    let relay_chain_interface = Arc::new(DummyRelayChainInterface::from_storage(vec![
+        (
+            well_known_keys::SESSION_INDEX.to_vec(),
+            orch_session.encode(),
+        ),
+        (
+            well_known_keys::authority_assignment_for_session(
+                orch_session,
+                Some(well_known_keys::SOLOCHAIN_AUTHORITY_ASSIGNMENT_PREFIX),
+            ),
+            current_assignment.encode(),
+        ),
+        (next_assignment_key.clone(), next_assignment.encode()),
+    ]));
+    let relay_hash = relay_chain_interface.relay_client.genesis_hash();
+    let relay_state_root = genesis_header(&relay_chain_interface.relay_client).state_root;
+
+    let created = ContainerChainAuthoritiesInherentData::create_at_solochain(
+        relay_hash,
+        &relay_chain_interface,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        proved_value(
+            relay_state_root,
+            &created.relay_chain_state,
+            &next_assignment_key,
+        ),
+        Some(next_assignment.encode())
+    );
+}
+
+#[tokio::test]
+async fn test_container_chain_keys_are_proved() {
+    let orch_session = 1u32;
+    let orch_para_id = 1000u32;
+    let container_para_id: ParaId = 2000.into();
+    let para_info = dp_core::ParaInfo {
+        manager: [1u8; 32],
+        deposit: 100u128,
+        locked: Some(true),
+    };
+    let relay_storage = vec![
+        (
+            well_known_keys::RELAY_CURRENT_SLOT.to_vec(),
+            100u64.encode(),
+        ),
+        (
+            well_known_keys::registrar_para_info(container_para_id),
+            para_info.encode(),
+        ),
+    ];
+    let orchestrator_chain_interface = Arc::new(DummyOrchestratorChainInterface::new_with_storage(
+        orch_session,
+        vec![
+            (
+                well_known_keys::REGISTERED_PARA_IDS.to_vec(),
+                vec![container_para_id].encode(),
+            ),
+            (
+                well_known_keys::PAUSED_PARA_IDS.to_vec(),
+                vec![container_para_id].encode(),
+            ),
+        ],
+    ));
+    let orchestrator_header = genesis_header(&orchestrator_chain_interface.orchestrator_client);
+    let relay_chain_interface = Arc::new(DummyRelayChainInterface::new_with_storage(
+        orch_para_id.into(),
+        orchestrator_header.clone(),
+        relay_storage.clone(),
+    ));
+    let relay_hash = relay_chain_interface.relay_client.genesis_hash();
+    let relay_state_root = genesis_header(&relay_chain_interface.relay_client).state_root;
+
+    let created = ContainerChainAuthoritiesInherentData::create_at_for_para(
+        relay_hash,
+        &relay_chain_interface,
+        &orchestrator_chain_interface,
+        orch_para_id.into(),
+        Some(container_para_id),
+    )
+    .await
+    .unwrap();
+
+    for (key, value) in [
+        (
+            well_known_keys::REGISTERED_PARA_IDS,
+            vec![container_para_id].encode(),
+        ),
+        (
+            well_known_keys::PAUSED_PARA_IDS,
+            vec![container_para_id].encode(),
+        ),
+    ] {
+        assert_eq!(
+            proved_value(
+                orchestrator_header.state_root,
+                &created.orchestrator_chain_state,
+                key,
+            ),
+            Some(value)
+        );
+    }
+    for (key, value) in &relay_storage {
+        assert_eq!(
+            proved_value(relay_state_root, &created.relay_chain_state, key),
+            Some(value.clone())
+        );
+    }
+    // Proved even if missing from the relay chain state
+    assert_eq!(
+        proved_value(
+            relay_state_root,
+            &created.relay_chain_state,
+            well_known_keys::RELAY_EPOCH_RANDOMNESS,
+        ),
+        None
+    );
+
+    // Same in solochain mode, where the registrar state is part of the relay chain state
+    let mut solochain_storage = relay_storage;
+    solochain_storage.extend([
+        (
+            well_known_keys::SESSION_INDEX.to_vec(),
+            orch_session.encode(),
+        ),
+        (
+            well_known_keys::SOLOCHAIN_REGISTERED_PARA_IDS.to_vec(),
+            vec![container_para_id].encode(),
+        ),
+        (
+            well_known_keys::SOLOCHAIN_PAUSED_PARA_IDS.to_vec(),
+            Vec::<ParaId>::new().encode(),
+        ),
+    ]);
+    let relay_chain_interface = Arc::new(DummyRelayChainInterface::from_storage(
+        solochain_storage.clone(),
+    ));
+    let relay_hash = relay_chain_interface.relay_client.genesis_hash();
+    let relay_state_root = genesis_header(&relay_chain_interface.relay_client).state_root;
+
+    let created = ContainerChainAuthoritiesInherentData::create_at_solochain_for_para(
+        relay_hash,
+        &relay_chain_interface,
+        Some(container_para_id),
+    )
+    .await
+    .unwrap();
+
+    for (key, value) in &solochain_storage {
+        assert_eq!(
+            proved_value(relay_state_root, &created.relay_chain_state, key),
+            Some(value.clone())
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_authority_session_keys_are_proved() {
+    let orch_session = 1u32;
+    let orch_para_id = 1000u32;
+    let container_para_id: ParaId = 2000.into();
+    let with_keys = NimbusId::from_slice(&[1u8; 32]).unwrap();
+    let without_keys = NimbusId::from_slice(&[2u8; 32]).unwrap();
+    let assignment = AssignedCollators::<NimbusId> {
+        orchestrator_chain: vec![],
+        container_chains: BTreeMap::from_iter([(
+            container_para_id,
+            vec![with_keys.clone(), without_keys.clone()],
+        )]),
+    };
+    let owner = [7u8; 32];
+    let next_keys = vec![8u8; 64];
+    let key_owner = |authority: &NimbusId| {
+        well_known_keys::session_key_owner(nimbus_primitives::NIMBUS_KEY_ID, authority.as_slice())
+    };
+    let orchestrator_chain_interface = Arc::new(DummyOrchestratorChainInterface::new_with_storage(
+        orch_session,
+        vec![
+            (
+                well_known_keys::authority_assignment_for_session(orch_session, None),
+                assignment.encode(),
+            ),
+            (key_owner(&with_keys), owner.encode()),
+            (
+                well_known_keys::session_next_keys(&owner.encode()),
+                next_keys.clone(),
+            ),
+        ],
+    ));
+    let orchestrator_header = genesis_header(&orchestrator_chain_interface.orchestrator_client);
+    let relay_chain_interface = Arc::new(DummyRelayChainInterface::new(
+        orch_para_id.into(),
+        orchestrator_header.clone(),
+    ));
+    let relay_hash = relay_chain_interface.relay_client.genesis_hash();
+
+    let created = ContainerChainAuthoritiesInherentData::create_at_for_para(
+        relay_hash,
+        &relay_chain_interface,
+        &orchestrator_chain_interface,
+        orch_para_id.into(),
+        Some(container_para_id),
+    )
+    .await
+    .unwrap();
+    let orchestrator_value = |key: &[u8]| {
+        proved_value(
+            orchestrator_header.state_root,
+            &created.orchestrator_chain_state,
+            key,
+        )
+    };
+
+    assert_eq!(
+        orchestrator_value(&key_owner(&with_keys)),
+        Some(owner.encode())
+    );
+    assert_eq!(
+        orchestrator_value(&well_known_keys::session_next_keys(&owner.encode())),
+        Some(next_keys)
+    );
+    // The authorities without session keys are proved to have none
+    assert_eq!(orchestrator_value(&key_owner(&without_keys)), None);
+}
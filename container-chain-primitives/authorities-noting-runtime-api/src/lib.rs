@@ -0,0 +1,64 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Runtime API to query the authorities noted by pallet-cc-authorities-noting
+
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+use {
+    alloc::vec::Vec,
+    cumulus_primitives_core::ParaId,
+    parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen},
+    scale_info::TypeInfo,
+};
+
+/// Block production of an author during an orchestrator session
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct BlockProductionStats {
+    /// Blocks authored
+    pub authored: u32,
+    /// Slots assigned to the author in which no block was produced
+    pub missed: u32,
+}
+
+sp_api::decl_runtime_apis! {
+    /// API to query the authorities of a container chain, as noted from the orchestrator.
+    pub trait AuthoritiesNotingApi<AuthorityId>
+    where AuthorityId: Codec
+    {
+        /// Returns the authorities currently allowed to author blocks
+        fn authorities() -> Vec<AuthorityId>;
+
+        /// Returns the para id of the orchestrator chain
+        fn orchestrator_para_id() -> ParaId;
+
+        /// Returns the orchestrator session of the last successfully noted authorities
+        fn last_noted_session() -> Option<u32>;
+
+        /// Returns the authority expected to author a block in the given slot, if any
+        fn expected_author(slot: u32) -> Option<AuthorityId>;
+    }
+
+    /// API to query the block production of the authors of a container chain.
+    pub trait BlockProductionStatsApi<AuthorityId>
+    where AuthorityId: Codec
+    {
+        /// Returns the blocks authored and the slots missed by each author in the given
+        /// orchestrator session
+        fn block_production_stats(session_index: u32) -> Vec<(AuthorityId, BlockProductionStats)>;
+    }
+}
@@ -20,7 +20,9 @@ extern crate alloc;
 pub use cumulus_primitives_core::ParaId;
 
 use {
-    sp_core::{Decode, Encode},
+    parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen},
+    scale_info::TypeInfo,
+    sp_core::RuntimeDebug,
     sp_runtime::{
         generic,
         traits::{BlakeTwo256, IdentifyAccount, Verify},
@@ -64,7 +66,18 @@ pub type BlockId = generic::BlockId<Block>;
 
 // Copied from polkadot-sdk because its fields are not public
 // polkadot-sdk/polkadot/runtime/common/src/paras_registrar/mod.rs
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
 pub struct ParaInfo<Account, Balance> {
     /// The account that has placed a deposit for registering this para.
     pub manager: Account,
@@ -78,7 +91,10 @@ pub struct ParaInfo<Account, Balance> {
 /// A declarations of storage keys where an external observer can find some interesting data.
 pub mod well_known_keys {
     use {
-        alloc::vec::Vec, cumulus_primitives_core::ParaId, sp_core::Encode, sp_io::hashing::twox_64,
+        alloc::vec::Vec,
+        cumulus_primitives_core::ParaId,
+        sp_core::{crypto::KeyTypeId, Encode},
+        sp_io::hashing::twox_64,
     };
 
     // They key to retrieve the para heads
@@ -124,6 +140,38 @@ pub mod well_known_keys {
     pub const SESSION_INDEX: &[u8] =
         &hex_literal::hex!["cec5070d609dd3497f72bde07fc96ba072763800a36a99fdfc7c10f6415f6ee6"];
 
+    /// session->keyOwner pallet prefix
+    pub const SESSION_KEY_OWNER_PREFIX: &[u8] =
+        &hex_literal::hex!["cec5070d609dd3497f72bde07fc96ba0726380404683fc89e8233450c8aa1950"];
+
+    /// session->nextKeys pallet prefix
+    pub const SESSION_NEXT_KEYS_PREFIX: &[u8] =
+        &hex_literal::hex!["cec5070d609dd3497f72bde07fc96ba04c014e6bf8b8c2c011e7290b85696bb3"];
+
+    // Retrieves the full key for session->keyOwner given the type and the raw public key, which
+    // maps to the validator that owns the key
+    pub fn session_key_owner(key_type: KeyTypeId, key: &[u8]) -> Vec<u8> {
+        (key_type, key).using_encoded(|owned_key| {
+            SESSION_KEY_OWNER_PREFIX
+                .iter()
+                .chain(twox_64(owned_key).iter())
+                .chain(owned_key.iter())
+                .copied()
+                .collect()
+        })
+    }
+
+    // Retrieves the full key for session->nextKeys given the encoded validator id, which maps to
+    // all the session keys of the validator
+    pub fn session_next_keys(validator: &[u8]) -> Vec<u8> {
+        SESSION_NEXT_KEYS_PREFIX
+            .iter()
+            .chain(twox_64(validator).iter())
+            .chain(validator.iter())
+            .copied()
+            .collect()
+    }
+
     // Retrieves the full key for registrar->paras given the paraId
     pub fn registrar_para_info(para_id: ParaId) -> Vec<u8> {
         para_id.using_encoded(|para_id| {
@@ -136,6 +184,35 @@ pub mod well_known_keys {
         })
     }
 
+    /// registrar->registeredParaIds, the container chains currently active in the orchestrator
+    pub const REGISTERED_PARA_IDS: &[u8] =
+        &hex_literal::hex!["3fba98689ebed1138735e0e7a5a790ab6339d4183899cf4f5efccdad995b795c"];
+
+    /// registrar->paused, the container chains paused in the orchestrator
+    pub const PAUSED_PARA_IDS: &[u8] =
+        &hex_literal::hex!["3fba98689ebed1138735e0e7a5a790ab51d47f736dfc5807b7a3033e859a8dfd"];
+
+    /// containerRegistrar->registeredParaIds instead of registrar->registeredParaIds for
+    /// solochain
+    pub const SOLOCHAIN_REGISTERED_PARA_IDS: &[u8] =
+        &hex_literal::hex!["bac7fde9d24e6a0282fa90d6c52a3f3a6339d4183899cf4f5efccdad995b795c"];
+
+    /// containerRegistrar->paused instead of registrar->paused for solochain
+    pub const SOLOCHAIN_PAUSED_PARA_IDS: &[u8] =
+        &hex_literal::hex!["bac7fde9d24e6a0282fa90d6c52a3f3a51d47f736dfc5807b7a3033e859a8dfd"];
+
+    /// babe->epochIndex of the relay chain
+    pub const RELAY_EPOCH_INDEX: &[u8] =
+        &hex_literal::hex!["1cb6f36e027abb2091cfb5110ab5087f38316cbf8fa0da822a20ac1c55bf1be3"];
+
+    /// babe->currentSlot of the relay chain
+    pub const RELAY_CURRENT_SLOT: &[u8] =
+        &hex_literal::hex!["1cb6f36e027abb2091cfb5110ab5087f06155b3cd9a8c9e5e9a23fd5dc13a5ed"];
+
+    /// babe->randomness of the relay chain, the randomness of its current epoch
+    pub const RELAY_EPOCH_RANDOMNESS: &[u8] =
+        &hex_literal::hex!["1cb6f36e027abb2091cfb5110ab5087f7a414cb008e0e61e46722aa60abdd672"];
+
     pub const REGISTRAR_PARAS_INDEX: &[u8] =
         &hex_literal::hex!["3fba98689ebed1138735e0e7a5a790abcd710b30bd2eab0352ddcc26417aa194"];
 }
@@ -19,15 +19,24 @@
 //! Test that code generated by dp-impl-tanssi-pallets-config macro compiles.
 //!
 //! We never execute this runtime so the config values can be wrong, we just want to verify that it
-//! compiles. This helps detect when the macro breaks because new config types are added.
+//! compiles. This helps detect when the macro breaks because new config types are added. The
+//! runtime APIs of `pallet_cc_authorities_noting` are implemented as documented in
+//! `dp_impl_tanssi_pallets_config::authorities_noting_runtime_api`.
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use cumulus_pallet_parachain_system::{ParachainSetCode, RelayNumberMonotonicallyIncreases};
-use cumulus_primitives_core::AggregateMessageOrigin;
+use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
+use dp_impl_tanssi_pallets_config::authorities_noting_runtime_api::{
+    AuthoritiesNotingApi, BlockProductionStats, BlockProductionStatsApi,
+};
 use dp_impl_tanssi_pallets_config::impl_tanssi_pallets_config;
 use frame_support::traits::{ConstU16, ConstU64, EnqueueWithOrigin};
 use frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND;
 use frame_support::{construct_runtime, parameter_types};
 use nimbus_primitives::{NimbusId, SlotBeacon};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_slots::{Slot, SlotDuration};
 use sp_core::{ConstBool, ConstU32, H256};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
@@ -153,3 +162,39 @@ impl dp_impl_tanssi_pallets_config::Config for Runtime {
 }
 
 impl_tanssi_pallets_config!(Runtime);
+
+sp_api::impl_runtime_apis! {
+    impl AuthoritiesNotingApi<Block, NimbusId> for Runtime {
+        fn authorities() -> Vec<NimbusId> {
+            AuthoritiesNoting::authorities()
+        }
+
+        fn orchestrator_para_id() -> ParaId {
+            AuthoritiesNoting::orchestrator_para_id()
+        }
+
+        fn last_noted_session() -> Option<u32> {
+            AuthoritiesNoting::last_noted_session()
+        }
+
+        fn expected_author(slot: u32) -> Option<NimbusId> {
+            AuthoritiesNoting::expected_author(slot)
+        }
+    }
+
+    impl BlockProductionStatsApi<Block, NimbusId> for Runtime {
+        fn block_production_stats(session_index: u32) -> Vec<(NimbusId, BlockProductionStats)> {
+            AuthoritiesNoting::block_production_stats(session_index)
+        }
+    }
+
+    impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+        fn slot_duration() -> sp_consensus_aura::SlotDuration {
+            sp_consensus_aura::SlotDuration::from_millis(SLOT_DURATION)
+        }
+
+        fn authorities() -> Vec<AuraId> {
+            AuthoritiesNoting::aura_authorities()
+        }
+    }
+}
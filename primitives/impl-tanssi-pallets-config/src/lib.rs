@@ -16,21 +16,152 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+/// Runtime APIs exposing the authorities noted by `pallet_cc_authorities_noting` and the block
+/// production of their authors. Runtimes using [`impl_tanssi_pallets_config`] can implement them in
+/// `impl_runtime_apis!` by forwarding each method to the pallet, as the runtime of
+/// `dp-impl-tanssi-pallets-config-test` does:
+///
+/// ```ignore
+/// impl dp_impl_tanssi_pallets_config::authorities_noting_runtime_api::AuthoritiesNotingApi<
+///     Block,
+///     NimbusId,
+/// > for Runtime
+/// {
+///     fn authorities() -> Vec<NimbusId> {
+///         AuthoritiesNoting::authorities()
+///     }
+///
+///     fn orchestrator_para_id() -> ParaId {
+///         AuthoritiesNoting::orchestrator_para_id()
+///     }
+///
+///     fn last_noted_session() -> Option<u32> {
+///         AuthoritiesNoting::last_noted_session()
+///     }
+///
+///     fn expected_author(slot: u32) -> Option<NimbusId> {
+///         AuthoritiesNoting::expected_author(slot)
+///     }
+/// }
+///
+/// impl dp_impl_tanssi_pallets_config::authorities_noting_runtime_api::BlockProductionStatsApi<
+///     Block,
+///     NimbusId,
+/// > for Runtime
+/// {
+///     fn block_production_stats(
+///         session_index: u32,
+///     ) -> Vec<(NimbusId, dp_impl_tanssi_pallets_config::authorities_noting_runtime_api::BlockProductionStats)> {
+///         AuthoritiesNoting::block_production_stats(session_index)
+///     }
+/// }
+/// ```
+///
+/// Tooling that reads the authorities from the aura runtime API keeps working if the runtime also
+/// implements it on top of the noted authorities:
+///
+/// ```ignore
+/// impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+///     fn slot_duration() -> sp_consensus_aura::SlotDuration {
+///         sp_consensus_aura::SlotDuration::from_millis(
+///             <Runtime as dp_impl_tanssi_pallets_config::Config>::SLOT_DURATION,
+///         )
+///     }
+///
+///     fn authorities() -> Vec<AuraId> {
+///         AuthoritiesNoting::aura_authorities()
+///     }
+/// }
+/// ```
+///
+/// Pallets that need the author of the block as an account, such as the EVM ones, can use
+/// `pallet_cc_authorities_noting::FindMappedAuthor` with
+/// `pallet_cc_authorities_noting::TruncatedH160Lookup` or `dp_consensus::NimbusLookUp`.
+pub use ccp_authorities_noting_runtime_api as authorities_noting_runtime_api;
+
 #[doc(hidden)]
 pub mod deps {
     pub use {
         frame_support, impls::impls, pallet_author_inherent, pallet_cc_authorities_noting,
-        pallet_timestamp,
+        pallet_timestamp, sp_runtime,
     };
 }
 
+use {
+    alloc::vec::Vec, core::marker::PhantomData, frame_support::traits::Get,
+    frame_system::pallet_prelude::BlockNumberFor, pallet_cc_authorities_noting::ProofFailurePolicy,
+};
+
 pub trait Config {
     const SLOT_DURATION: u64;
+    /// Number of orchestrator sessions kept in the authorities history of
+    /// `pallet_cc_authorities_noting`.
+    const AUTHORITIES_HISTORY_DEPTH: u32 = 10;
+    /// Maximum number of authorities noted by `pallet_cc_authorities_noting`.
+    const MAX_AUTHORITIES: u32 = 100;
+    /// Maximum number of orchestrator collators noted by `pallet_cc_authorities_noting`.
+    const MAX_ORCHESTRATOR_AUTHORITIES: u32 = 100;
+    /// What `pallet_cc_authorities_noting` does when the inherent carries a malformed proof.
+    const AUTHORITIES_NOTING_MALFORMED_PROOF_POLICY: ProofFailurePolicy = ProofFailurePolicy::Panic;
+    /// Number of blocks during which `pallet_cc_authorities_noting` keeps the last noted
+    /// authorities after noting starts failing.
+    const AUTHORITIES_GRACE_PERIOD: u32 = 0;
+    /// Duration of a relay chain slot, in milliseconds.
+    const RELAY_SLOT_DURATION: u64 = 6000;
+    /// Maximum difference, in milliseconds, between the timestamp of a block and the start of the
+    /// relay slot proved by `pallet_cc_authorities_noting`. `None` disables the check.
+    const MAX_RELAY_TIME_DRIFT: Option<u64> = None;
     type TimestampWeights: pallet_timestamp::weights::WeightInfo;
     type AuthorInherentWeights: pallet_author_inherent::weights::WeightInfo;
     type AuthoritiesNotingWeights: pallet_cc_authorities_noting::weights::WeightInfo;
 }
 
+/// Exposes [`Config::AUTHORITIES_NOTING_MALFORMED_PROOF_POLICY`] as a [`Get`] type.
+#[doc(hidden)]
+pub struct MalformedProofPolicyOf<R>(PhantomData<R>);
+
+impl<R: Config> Get<ProofFailurePolicy> for MalformedProofPolicyOf<R> {
+    fn get() -> ProofFailurePolicy {
+        R::AUTHORITIES_NOTING_MALFORMED_PROOF_POLICY
+    }
+}
+
+/// Exposes [`Config::AUTHORITIES_GRACE_PERIOD`] as a [`Get`] type.
+#[doc(hidden)]
+pub struct AuthoritiesGracePeriodOf<R>(PhantomData<R>);
+
+impl<R: Config + frame_system::Config> Get<BlockNumberFor<R>> for AuthoritiesGracePeriodOf<R> {
+    fn get() -> BlockNumberFor<R> {
+        R::AUTHORITIES_GRACE_PERIOD.into()
+    }
+}
+
+/// Exposes [`Config::MAX_RELAY_TIME_DRIFT`] as a [`Get`] type.
+#[doc(hidden)]
+pub struct MaxRelayTimeDriftOf<R>(PhantomData<R>);
+
+impl<R: Config> Get<Option<u64>> for MaxRelayTimeDriftOf<R> {
+    fn get() -> Option<u64> {
+        R::MAX_RELAY_TIME_DRIFT
+    }
+}
+
+/// Genesis config of `pallet_cc_authorities_noting` with preset authorities, for the genesis
+/// presets and chain specs of runtimes using [`impl_tanssi_pallets_config`]. The authorities are
+/// used until the first orchestrator assignment is noted.
+pub fn authorities_noting_genesis<R: pallet_cc_authorities_noting::Config>(
+    orchestrator_para_id: u32,
+    authorities: Vec<R::AuthorityId>,
+) -> pallet_cc_authorities_noting::GenesisConfig<R> {
+    pallet_cc_authorities_noting::GenesisConfig {
+        orchestrator_para_id: orchestrator_para_id.into(),
+        authorities,
+        ..Default::default()
+    }
+}
+
 /// Implements Config traits for `pallet_author_inherent`, `pallet_timestamp` and
 /// `pallet_cc_authorities_noting` with the proper parameters to be compatible with Tanssi.
 /// Requires to implement the [`Config`] trait on the runtime.
@@ -69,6 +200,29 @@ macro_rules! impl_tanssi_pallets_config {
                 type RelayChainStateProvider =
                     cumulus_pallet_parachain_system::RelaychainDataProvider<Self>;
                 type AuthorityId = NimbusId;
+                type MaxAuthorities =
+                    frame_support::traits::ConstU32<{ <$runtime as $crate::Config>::MAX_AUTHORITIES }>;
+                type MaxOrchestratorAuthorities = frame_support::traits::ConstU32<
+                    { <$runtime as $crate::Config>::MAX_ORCHESTRATOR_AUTHORITIES },
+                >;
+                type AuthoritiesHistoryDepth = frame_support::traits::ConstU32<
+                    { <$runtime as $crate::Config>::AUTHORITIES_HISTORY_DEPTH },
+                >;
+                type MalformedProofPolicy = $crate::MalformedProofPolicyOf<$runtime>;
+                type AuthoritiesGracePeriod = $crate::AuthoritiesGracePeriodOf<$runtime>;
+                type OnAuthoritiesChanged = ();
+                type FindAuthor = pallet_author_inherent::Pallet<$runtime>;
+                type SlotBeacon = <Self as pallet_author_inherent::Config>::SlotBeacon;
+                type AuthorSelectionStrategy = pallet_cc_authorities_noting::RoundRobin;
+                type ManagerAccountId = sp_runtime::AccountId32;
+                type RegistrarBalance = u128;
+                type OrchestratorValidatorId = sp_runtime::AccountId32;
+                type OrchestratorSessionKeys = ();
+                type NoteAuthoritySessionKeys = frame_support::traits::ConstBool<false>;
+                type UnixTime = pallet_timestamp::Pallet<$runtime>;
+                type RelaySlotDuration =
+                    ConstU64<{ <$runtime as $crate::Config>::RELAY_SLOT_DURATION }>;
+                type MaxRelayTimeDrift = $crate::MaxRelayTimeDriftOf<$runtime>;
                 type WeightInfo = <$runtime as $crate::Config>::AuthoritiesNotingWeights;
 
                 #[cfg(feature = "runtime-benchmarks")]
@@ -57,6 +57,8 @@ impl Default for ParaHeaderSproofBuilderItem {
 #[derive(Clone, Default)]
 pub struct ParaHeaderSproofBuilder {
     pub items: Vec<ParaHeaderSproofBuilderItem>,
+    /// Additional raw key-values inserted and proved along with the para heads
+    pub extra_storage: Vec<(Vec<u8>, Vec<u8>)>,
 }
 
 impl ParaHeaderSproofBuilder {
@@ -92,6 +94,9 @@ impl ParaHeaderSproofBuilder {
                 let head_data: HeadData = encoded.into();
                 insert(key, head_data.encode());
             }
+            for (key, value) in self.extra_storage {
+                insert(key, value);
+            }
         }
 
         let root = *backend.root();
@@ -174,6 +179,10 @@ impl ParaHeaderSproofBuilder {
 pub struct AuthorityAssignmentSproofBuilder<T> {
     pub session_index: u32,
     pub authority_assignment: AssignedCollators<T>,
+    /// The assignment for `session_index + 1`, only inserted if set
+    pub next_authority_assignment: Option<AssignedCollators<T>>,
+    /// Additional raw key-values inserted and proved along with the assignment
+    pub extra_storage: Vec<(Vec<u8>, Vec<u8>)>,
 }
 
 impl<T: Encode> AuthorityAssignmentSproofBuilder<T> {
@@ -203,6 +212,16 @@ impl<T: Encode> AuthorityAssignmentSproofBuilder<T> {
             well_known_keys::authority_assignment_for_session(self.session_index, None).to_vec(),
             self.authority_assignment.encode(),
         );
+        if let Some(next_authority_assignment) = self.next_authority_assignment {
+            insert(
+                well_known_keys::authority_assignment_for_session(self.session_index + 1, None)
+                    .to_vec(),
+                next_authority_assignment.encode(),
+            );
+        }
+        for (key, value) in self.extra_storage {
+            insert(key, value);
+        }
 
         let root = *backend.root();
         let proof = sp_state_machine::prove_read(backend, relevant_keys).expect("prove read");
@@ -240,6 +259,19 @@ impl<T: Encode> AuthorityAssignmentSproofBuilder<T> {
             .to_vec(),
             self.authority_assignment.encode(),
         );
+        if let Some(next_authority_assignment) = self.next_authority_assignment {
+            insert(
+                well_known_keys::authority_assignment_for_session(
+                    self.session_index + 1,
+                    Some(well_known_keys::SOLOCHAIN_AUTHORITY_ASSIGNMENT_PREFIX),
+                )
+                .to_vec(),
+                next_authority_assignment.encode(),
+            );
+        }
+        for (key, value) in self.extra_storage {
+            insert(key, value);
+        }
 
         let root = *backend.root();
         let proof = sp_state_machine::prove_read(backend, relevant_keys).expect("prove read");